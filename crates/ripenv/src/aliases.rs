@@ -0,0 +1,308 @@
+//! Command aliases, resolved before clap subcommand dispatch.
+//!
+//! Mirrors how cargo resolves `[alias]` entries from `.cargo/config.toml`
+//! before handing off to its own subcommand dispatch: if the first
+//! argument isn't a built-in `ripenv` subcommand, look it up among the
+//! configured aliases and splice its expansion into the argument vector
+//! in place of the alias name.
+//!
+//! Aliases can come from two places, merged with [`merged_aliases`]:
+//!
+//! - The Pipfile's `[aliases]` table (the original source).
+//! - A standalone `ripenv.toml`'s `[aliases]` table, which takes
+//!   precedence on a name collision — it's the more specific,
+//!   ripenv-only config file and is expected to be the project's
+//!   preferred place for them going forward.
+//!
+//! An alias whose name collides with a built-in subcommand is always
+//! dropped rather than allowed to shadow it, with a warning printed to
+//! stderr so the collision isn't silent.
+
+use std::collections::{BTreeMap, HashSet};
+use std::ffi::OsString;
+
+use anstream::eprintln;
+use clap::CommandFactory;
+use owo_colors::OwoColorize;
+use serde::Deserialize;
+
+use crate::cli::Cli;
+use crate::pipfile::model::{Pipfile, PipfileAlias};
+
+/// Maximum alias expansions to follow before giving up. Guards against
+/// self-referential or mutually-recursive alias chains; a single alias
+/// expanding through a handful of others is normal, an infinite loop is not.
+const MAX_EXPANSIONS: usize = 8;
+
+/// Schema of a standalone `ripenv.toml` config file.
+///
+/// Currently holds only `[aliases]`, mirroring the Pipfile's table of the
+/// same name; this is the place future ripenv-only (non-Pipfile) settings
+/// would go.
+#[derive(Debug, Default, Deserialize)]
+struct RipenvToml {
+    #[serde(default)]
+    aliases: BTreeMap<String, PipfileAlias>,
+}
+
+impl RipenvToml {
+    /// Load the nearest `ripenv.toml` from the current directory, if any.
+    ///
+    /// Best-effort, like [`load_pipfile`]: discovery or parse failures are
+    /// swallowed since alias resolution must never block a command.
+    fn load() -> Option<Self> {
+        let cwd = std::env::current_dir().ok()?;
+        let path = crate::pipfile::discovery::find_ripenv_toml(&cwd)?;
+        let content = fs_err::read_to_string(&path).ok()?;
+        toml::from_str(&content).ok()
+    }
+}
+
+/// Resolve a leading alias in `args`, if any.
+///
+/// `args` is the full argv, including the program name at index 0.
+/// Returns `args` unchanged if no alias source defines a matching alias,
+/// or the first argument is already a built-in subcommand (built-ins
+/// always take precedence over aliases).
+pub fn resolve(args: Vec<OsString>) -> Vec<OsString> {
+    let builtin_names: HashSet<String> = Cli::command()
+        .get_subcommands()
+        .map(|cmd| cmd.get_name().to_owned())
+        .collect();
+    let aliases = merged_aliases(&builtin_names);
+
+    resolve_with(args, &aliases, &builtin_names)
+}
+
+/// Combine the Pipfile's `[aliases]` table with a standalone
+/// `ripenv.toml`'s, if present. On a name collision, `ripenv.toml` wins.
+///
+/// Any alias whose name matches a built-in subcommand is dropped, with a
+/// warning printed to stderr, rather than silently letting the built-in
+/// shadow it (or vice versa, depending on resolution order).
+pub fn merged_aliases(builtin_names: &HashSet<String>) -> BTreeMap<String, PipfileAlias> {
+    combine_aliases(
+        load_pipfile()
+            .map(|pipfile| pipfile.aliases)
+            .unwrap_or_default(),
+        RipenvToml::load()
+            .map(|ripenv_toml| ripenv_toml.aliases)
+            .unwrap_or_default(),
+        builtin_names,
+    )
+}
+
+/// The merge and shadowing rules behind [`merged_aliases`], decoupled from
+/// alias discovery so they can be exercised directly in tests.
+fn combine_aliases(
+    mut aliases: BTreeMap<String, PipfileAlias>,
+    ripenv_toml_aliases: BTreeMap<String, PipfileAlias>,
+    builtin_names: &HashSet<String>,
+) -> BTreeMap<String, PipfileAlias> {
+    aliases.extend(ripenv_toml_aliases);
+
+    aliases.retain(|name, _| {
+        if builtin_names.contains(name) {
+            eprintln!(
+                "{}: alias \"{name}\" shadows a built-in subcommand and will be ignored",
+                "warning".yellow().bold(),
+            );
+            false
+        } else {
+            true
+        }
+    });
+
+    aliases
+}
+
+/// Core alias-splicing logic, decoupled from alias discovery so it can be
+/// exercised directly in tests.
+fn resolve_with(
+    args: Vec<OsString>,
+    aliases: &BTreeMap<String, PipfileAlias>,
+    builtin_names: &HashSet<String>,
+) -> Vec<OsString> {
+    let Some(first) = args.get(1).and_then(|s| s.to_str()) else {
+        return args;
+    };
+    if first.starts_with('-') || builtin_names.contains(first) {
+        return args;
+    }
+
+    let program = args[0].clone();
+    let mut trailing: Vec<OsString> = args[2..].to_vec();
+    let mut alias_name = first.to_owned();
+    let mut seen = HashSet::new();
+
+    for _ in 0..MAX_EXPANSIONS {
+        let Some(alias) = aliases.get(&alias_name) else {
+            // Not a known alias either; let clap report "unrecognized
+            // subcommand" the normal way.
+            return args;
+        };
+        if !seen.insert(alias_name.clone()) {
+            // Alias refers back to one already expanded in this chain.
+            // Give up and let clap treat the original name literally.
+            return args;
+        }
+
+        let expansion = alias.expand();
+        let Some((head, tail)) = expansion.split_first() else {
+            return args;
+        };
+
+        if builtin_names.contains(head) {
+            let mut new_args = Vec::with_capacity(2 + tail.len() + trailing.len());
+            new_args.push(program);
+            new_args.push(OsString::from(head));
+            new_args.extend(tail.iter().map(OsString::from));
+            new_args.extend(trailing);
+            return new_args;
+        }
+
+        // The alias expanded to another alias name; keep following it,
+        // carrying the rest of its expansion along as trailing args.
+        let mut next_trailing: Vec<OsString> = tail.iter().map(OsString::from).collect();
+        next_trailing.extend(trailing);
+        trailing = next_trailing;
+        alias_name = head.clone();
+    }
+
+    args
+}
+
+/// Names of all configured aliases (Pipfile and/or `ripenv.toml`).
+///
+/// Used by [`crate::suggest`] to include user aliases in "did you mean"
+/// suggestions for unrecognized subcommands.
+pub fn alias_names() -> HashSet<String> {
+    let builtin_names: HashSet<String> = Cli::command()
+        .get_subcommands()
+        .map(|cmd| cmd.get_name().to_owned())
+        .collect();
+    merged_aliases(&builtin_names).into_keys().collect()
+}
+
+/// Load the nearest Pipfile from the current directory, if any.
+///
+/// Best-effort: any discovery or parse failure is swallowed, since alias
+/// resolution is a convenience layer that must never block a command
+/// (including `ripenv install`, which is what creates the Pipfile).
+fn load_pipfile() -> Option<Pipfile> {
+    let cwd = std::env::current_dir().ok()?;
+    let path = crate::pipfile::find_pipfile(&cwd).ok()?;
+    Pipfile::from_path(&path).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aliases_map(entries: &[(&str, PipfileAlias)]) -> BTreeMap<String, PipfileAlias> {
+        entries
+            .iter()
+            .map(|(name, alias)| ((*name).to_owned(), alias.clone()))
+            .collect()
+    }
+
+    fn builtins() -> HashSet<String> {
+        Cli::command()
+            .get_subcommands()
+            .map(|cmd| cmd.get_name().to_owned())
+            .collect()
+    }
+
+    fn args(parts: &[&str]) -> Vec<OsString> {
+        parts.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn builtin_command_bypasses_aliases() {
+        let aliases = aliases_map(&[("install", PipfileAlias::Line("lock".to_owned()))]);
+        let resolved = resolve_with(args(&["ripenv", "install"]), &aliases, &builtins());
+        assert_eq!(resolved, args(&["ripenv", "install"]));
+    }
+
+    #[test]
+    fn alias_expands_to_builtin_command() {
+        let aliases = aliases_map(&[(
+            "ci",
+            PipfileAlias::Line("install --deploy --no-dev".to_owned()),
+        )]);
+        let resolved = resolve_with(args(&["ripenv", "ci"]), &aliases, &builtins());
+        assert_eq!(
+            resolved,
+            args(&["ripenv", "install", "--deploy", "--no-dev"])
+        );
+    }
+
+    #[test]
+    fn alias_preserves_trailing_args() {
+        let aliases =
+            aliases_map(&[("ci", PipfileAlias::Line("install --deploy".to_owned()))]);
+        let resolved = resolve_with(
+            args(&["ripenv", "ci", "requests"]),
+            &aliases,
+            &builtins(),
+        );
+        assert_eq!(
+            resolved,
+            args(&["ripenv", "install", "--deploy", "requests"])
+        );
+    }
+
+    #[test]
+    fn unknown_leading_argument_is_left_alone() {
+        let aliases = aliases_map(&[]);
+        let resolved = resolve_with(args(&["ripenv", "bogus"]), &aliases, &builtins());
+        assert_eq!(resolved, args(&["ripenv", "bogus"]));
+    }
+
+    #[test]
+    fn self_referential_alias_is_left_alone() {
+        let aliases = aliases_map(&[("loop", PipfileAlias::Line("loop".to_owned()))]);
+        let resolved = resolve_with(args(&["ripenv", "loop"]), &aliases, &builtins());
+        assert_eq!(resolved, args(&["ripenv", "loop"]));
+    }
+
+    #[test]
+    fn chained_aliases_resolve_to_builtin() {
+        let aliases = aliases_map(&[
+            ("ci", PipfileAlias::Line("fast-install".to_owned())),
+            (
+                "fast-install",
+                PipfileAlias::Line("install --deploy --no-dev".to_owned()),
+            ),
+        ]);
+        let resolved = resolve_with(args(&["ripenv", "ci"]), &aliases, &builtins());
+        assert_eq!(
+            resolved,
+            args(&["ripenv", "install", "--deploy", "--no-dev"])
+        );
+    }
+
+    #[test]
+    fn ripenv_toml_alias_overrides_pipfile_alias() {
+        let pipfile_aliases = aliases_map(&[("ci", PipfileAlias::Line("lock".to_owned()))]);
+        let merged = combine_aliases(
+            pipfile_aliases,
+            aliases_map(&[("ci", PipfileAlias::Line("install --deploy".to_owned()))]),
+            &builtins(),
+        );
+
+        let resolved = resolve_with(args(&["ripenv", "ci"]), &merged, &builtins());
+        assert_eq!(resolved, args(&["ripenv", "install", "--deploy"]));
+    }
+
+    #[test]
+    fn merged_aliases_drops_builtin_shadowing_name() {
+        let aliases = combine_aliases(
+            aliases_map(&[("install", PipfileAlias::Line("lock".to_owned()))]),
+            BTreeMap::new(),
+            &builtins(),
+        );
+
+        assert!(!aliases.contains_key("install"));
+    }
+}
@@ -34,6 +34,26 @@ pub struct Cli {
     /// Suppress all output.
     #[arg(global = true, short, long)]
     pub quiet: bool,
+
+    /// Output format. `json` emits one NDJSON object per event on stdout
+    /// instead of human-readable messages, for consumption by scripts
+    /// and CI pipelines.
+    #[arg(global = true, long, value_enum, default_value_t = OutputFormat::Human)]
+    pub format: OutputFormat,
+}
+
+/// Output format for ripenv's own command output.
+///
+/// Warnings, errors, and debug messages always go to stderr. Informational
+/// messages go to stdout under `Human`; under `Json` they move to stderr
+/// and each command emits structured NDJSON on stdout instead, so piping
+/// `ripenv update --format json` doesn't mix prose with machine-readable
+/// data.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Human,
+    Json,
 }
 
 /// Top-level subcommands for ripenv.
@@ -58,6 +78,12 @@ pub enum Commands {
     /// Run a command in the virtualenv, or a Pipfile script.
     Run(RunArgs),
 
+    /// Build a source distribution and/or wheel for the project.
+    Build(BuildArgs),
+
+    /// Manage standalone CLI tools installed into isolated global venvs.
+    Global(GlobalArgs),
+
     /// Spawn a shell with the virtualenv activated.
     Shell(ShellArgs),
 
@@ -81,6 +107,9 @@ pub enum Commands {
 
     /// Audit installed packages for known vulnerabilities.
     Audit(AuditArgs),
+
+    /// Generate shell completion scripts or man pages.
+    Completions(CompletionsArgs),
 }
 
 impl Commands {
@@ -93,6 +122,8 @@ impl Commands {
             Self::Sync(_) => "sync",
             Self::Update(_) => "update",
             Self::Run(_) => "run",
+            Self::Build(_) => "build",
+            Self::Global(_) => "global",
             Self::Shell(_) => "shell",
             Self::Graph(_) => "graph",
             Self::Requirements(_) => "requirements",
@@ -101,6 +132,7 @@ impl Commands {
             Self::Verify(_) => "verify",
             Self::Check(_) => "check",
             Self::Audit(_) => "audit",
+            Self::Completions(_) => "completions",
         }
     }
 }
@@ -147,8 +179,26 @@ pub struct InstallArgs {
     pub skip_lock: bool,
 
     /// Specify the package index to use.
-    #[arg(long)]
+    #[arg(short = 'i', long = "index-url", visible_alias = "index")]
     pub index: Option<String>,
+
+    /// Additional package index to make available for resolution, without
+    /// changing which index newly-added packages are pinned to.
+    ///
+    /// May be passed multiple times. Each URL becomes its own `[[source]]`
+    /// entry in the Pipfile, deduplicated by URL.
+    #[arg(long = "extra-index-url")]
+    pub extra_index_url: Vec<String>,
+
+    /// Keep all other locked pins as-is; only resolve the newly requested
+    /// packages freely.
+    ///
+    /// Mirrors pipenv's `--keep-outdated`: every already-locked package
+    /// prefers its current `uv.lock` resolution. `install` requests no
+    /// upgrade without the flag either, so both lock the same way; the
+    /// flag is accepted for pipenv compatibility.
+    #[arg(long = "keep-outdated")]
+    pub keep_outdated: bool,
 }
 
 impl InstallArgs {
@@ -195,6 +245,14 @@ pub struct LockArgs {
     /// Clear resolver caches.
     #[arg(long)]
     pub clear: bool,
+
+    /// Fail if any locked package ends up without a SHA256 hash.
+    ///
+    /// Backfills missing hashes from uv's local cache first; only fails
+    /// generation for packages still unhashed afterward. Intended for CI,
+    /// where pipenv's `--deploy` install expects a fully-hashed lockfile.
+    #[arg(long = "require-hashes")]
+    pub require_hashes: bool,
 }
 
 /// Arguments for `ripenv sync`.
@@ -235,12 +293,21 @@ pub struct UpdateArgs {
     /// Only update the lockfile, do not sync.
     #[arg(long)]
     pub lock_only: bool,
+
+    /// Report packages whose locked version trails what's newly
+    /// resolvable, without writing the lockfile or touching the
+    /// environment.
+    #[arg(long)]
+    pub outdated: bool,
 }
 
 /// Arguments for `ripenv run`.
 #[derive(Parser, Debug)]
 pub struct RunArgs {
     /// The command (or Pipfile script name) to run.
+    ///
+    /// A leading `+3.11`/`+pypy@3.10`-style token pins the interpreter for
+    /// this invocation only, e.g. `ripenv run +3.12 python script.py`.
     pub command: String,
 
     /// Arguments to pass to the command.
@@ -252,6 +319,101 @@ pub struct RunArgs {
     pub system: bool,
 }
 
+/// Arguments for `ripenv build`.
+#[derive(Parser, Debug)]
+pub struct BuildArgs {
+    /// Build a source distribution only.
+    #[arg(long)]
+    pub sdist: bool,
+
+    /// Build a wheel only.
+    #[arg(long)]
+    pub wheel: bool,
+
+    /// Directory to write the built artifacts to.
+    #[arg(long = "out-dir", default_value = "dist")]
+    pub out_dir: String,
+
+    /// Disable build isolation when invoking the build backend.
+    #[arg(long = "no-build-isolation")]
+    pub no_build_isolation: bool,
+
+    /// Build a specific package in a workspace, by name.
+    ///
+    /// Defaults to the root project when omitted.
+    #[arg(long)]
+    pub package: Option<String>,
+}
+
+impl BuildArgs {
+    /// Whether an sdist should be built (default: true unless `--wheel` only).
+    pub fn build_sdist(&self) -> bool {
+        self.sdist || !self.wheel
+    }
+
+    /// Whether a wheel should be built (default: true unless `--sdist` only).
+    pub fn build_wheel(&self) -> bool {
+        self.wheel || !self.sdist
+    }
+}
+
+/// Arguments for `ripenv global`.
+#[derive(Parser, Debug)]
+pub struct GlobalArgs {
+    #[command(subcommand)]
+    pub command: GlobalCommand,
+}
+
+/// Subcommands of `ripenv global`.
+#[derive(Subcommand, Debug)]
+pub enum GlobalCommand {
+    /// Install a standalone CLI into its own isolated venv.
+    Install(GlobalInstallArgs),
+
+    /// Remove a globally-installed tool and its venv.
+    Remove(GlobalRemoveArgs),
+
+    /// List globally-installed tools.
+    List(GlobalListArgs),
+
+    /// Reconcile installed tool venvs against `global.toml`.
+    Sync(GlobalSyncArgs),
+}
+
+/// Arguments for `ripenv global install`.
+#[derive(Parser, Debug)]
+pub struct GlobalInstallArgs {
+    /// The package to install (e.g. `black`, `ruff==0.6.0`).
+    pub package: String,
+
+    /// Allow pre-release versions.
+    #[arg(long)]
+    pub pre: bool,
+
+    /// Re-install even if the tool is already present in `global.toml`.
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Arguments for `ripenv global remove`.
+#[derive(Parser, Debug)]
+pub struct GlobalRemoveArgs {
+    /// Name of the tool to remove, as it appears in `global.toml`.
+    pub tool: String,
+}
+
+/// Arguments for `ripenv global list`.
+#[derive(Parser, Debug)]
+pub struct GlobalListArgs;
+
+/// Arguments for `ripenv global sync`.
+#[derive(Parser, Debug)]
+pub struct GlobalSyncArgs {
+    /// Show what would change without installing or removing anything.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 /// Arguments for `ripenv shell`.
 #[derive(Parser, Debug)]
 pub struct ShellArgs;
@@ -311,3 +473,27 @@ pub struct CheckArgs;
 /// Arguments for `ripenv audit`.
 #[derive(Parser, Debug)]
 pub struct AuditArgs;
+
+/// Arguments for `ripenv completions`.
+#[derive(Parser, Debug)]
+pub struct CompletionsArgs {
+    /// Shell to generate a completion script for.
+    #[arg(value_enum)]
+    pub shell: Option<ShellKind>,
+
+    /// Generate a roff man page instead of a shell completion script.
+    #[arg(long, conflicts_with = "shell")]
+    pub man: bool,
+}
+
+/// Shells supported by `ripenv completions`, extending clap_complete's
+/// built-in set with Nushell (via `clap_complete_nushell`).
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum ShellKind {
+    Bash,
+    Zsh,
+    Fish,
+    PowerShell,
+    Elvish,
+    Nushell,
+}
@@ -0,0 +1,80 @@
+//! `ripenv build` — produce a source distribution and/or wheel.
+//!
+//! Pipfile projects don't carry full packaging metadata (there's no
+//! `pyproject.toml` with a `[project]` table), so before delegating to uv's
+//! build backend we synthesize minimal project metadata into the generated
+//! virtual `pyproject.toml`: the name from [`project_name_from_dir`], a
+//! fixed placeholder version of `0.0.0`, and `requires-python` from the
+//! Pipfile's `[requires]` section.
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::Result;
+use uv_configuration::BuildOutput;
+use uv_normalize::PackageName;
+
+use crate::cli::BuildArgs;
+use crate::commands::ExitStatus;
+use crate::commands::uv_runner::UvContext;
+use crate::printer::Printer;
+
+/// Execute `ripenv build`.
+pub async fn execute(
+    args: &BuildArgs,
+    printer: Printer,
+    verbosity: u8,
+    quiet: bool,
+) -> Result<ExitStatus> {
+    let ctx = UvContext::discover(printer, verbosity, quiet)?;
+
+    let out_dir = PathBuf::from(&args.out_dir);
+
+    let package = args
+        .package
+        .as_deref()
+        .map(PackageName::from_str)
+        .transpose()?;
+
+    let cache = ctx.cache()?;
+
+    let build_output = if quiet {
+        BuildOutput::Quiet
+    } else {
+        BuildOutput::Stderr
+    };
+
+    let result = uv::commands::build::build(
+        &ctx.project_dir,
+        package.as_ref(),
+        Some(out_dir.clone()),
+        args.build_sdist(),
+        args.build_wheel(),
+        None, // list (don't just enumerate what would be built)
+        uv::settings::LockCheck::Disabled,
+        None, // script
+        ctx.install_mirrors(),
+        !args.no_build_isolation,
+        false, // force_pep517
+        ctx.client_builder(),
+        None, // python
+        ctx.python_preference(),
+        ctx.python_downloads(),
+        ctx.resolver_settings(),
+        ctx.concurrency(),
+        false, // no_config
+        &cache,
+        build_output,
+        ctx.preview(),
+    )
+    .await?;
+
+    if matches!(result, ExitStatus::Success) {
+        ctx.printer.info(&format!(
+            "Built artifacts written to {}",
+            out_dir.display()
+        ));
+    }
+
+    Ok(result)
+}
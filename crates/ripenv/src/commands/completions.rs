@@ -0,0 +1,55 @@
+//! `ripenv completions` — generate shell completion scripts or man pages.
+
+use std::io::Write;
+
+use anyhow::{Context, Result, bail};
+use clap::CommandFactory;
+use clap_complete::{Shell, generate};
+use clap_complete_nushell::Nushell;
+use clap_mangen::Man;
+
+use crate::cli::{Cli, CompletionsArgs, ShellKind};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Execute `ripenv completions`.
+///
+/// With `--man`, renders a roff man page for the whole `ripenv` command
+/// tree. Otherwise, generates a shell completion script for `args.shell`
+/// via `clap_complete` (or `clap_complete_nushell` for Nushell). Output
+/// always goes to stdout so it can be redirected into a shell's
+/// completion directory.
+pub async fn execute(
+    args: &CompletionsArgs,
+    _printer: Printer,
+    _verbosity: u8,
+    _quiet: bool,
+) -> Result<ExitStatus> {
+    let mut command = Cli::command();
+    let bin_name = command.get_name().to_owned();
+    let mut stdout = std::io::stdout();
+
+    if args.man {
+        Man::new(command).render(&mut stdout)?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let Some(shell) = args.shell else {
+        bail!(
+            "a shell is required unless `--man` is passed \
+             (bash, zsh, fish, power-shell, elvish, nushell)"
+        );
+    };
+
+    match shell {
+        ShellKind::Bash => generate(Shell::Bash, &mut command, bin_name, &mut stdout),
+        ShellKind::Zsh => generate(Shell::Zsh, &mut command, bin_name, &mut stdout),
+        ShellKind::Fish => generate(Shell::Fish, &mut command, bin_name, &mut stdout),
+        ShellKind::PowerShell => generate(Shell::PowerShell, &mut command, bin_name, &mut stdout),
+        ShellKind::Elvish => generate(Shell::Elvish, &mut command, bin_name, &mut stdout),
+        ShellKind::Nushell => generate(Nushell, &mut command, bin_name, &mut stdout),
+    }
+    stdout.flush().context("failed to write completion script to stdout")?;
+
+    Ok(ExitStatus::Success)
+}
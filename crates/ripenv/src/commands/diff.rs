@@ -0,0 +1,204 @@
+//! Summarize package changes in a virtualenv before/after a sync.
+//!
+//! [`PackageSnapshot`] captures name -> version for every installed
+//! distribution; [`diff_snapshots`] compares two snapshots and
+//! [`print_diff_summary`] renders the result through [`Printer`] using the
+//! `+`/`-`/`~` markers pipenv/uv users already recognize.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use uv_installer::SitePackages;
+use uv_python::PythonEnvironment;
+
+use crate::printer::Printer;
+
+/// Installed package name -> version, captured at one point in time.
+#[derive(Debug, Default, Clone)]
+pub struct PackageSnapshot(BTreeMap<String, String>);
+
+impl PackageSnapshot {
+    /// Capture the currently installed distributions in `environment`.
+    pub fn capture(environment: &PythonEnvironment) -> Result<Self> {
+        let site_packages = SitePackages::from_environment(environment)?;
+        let packages = site_packages
+            .iter()
+            .map(|dist| (dist.name().to_string(), dist.version().to_string()))
+            .collect();
+        Ok(Self(packages))
+    }
+}
+
+/// A single package-level change between two snapshots.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PackageChange {
+    /// The package was installed (it wasn't present before).
+    Added { name: String, version: String },
+    /// The package was removed (it isn't present after).
+    Removed { name: String, version: String },
+    /// The package's version changed.
+    Upgraded {
+        name: String,
+        from: String,
+        to: String,
+    },
+}
+
+/// Diff two snapshots, sorted by package name.
+pub fn diff_snapshots(before: &PackageSnapshot, after: &PackageSnapshot) -> Vec<PackageChange> {
+    let mut changes = Vec::new();
+
+    for (name, before_version) in &before.0 {
+        match after.0.get(name) {
+            None => changes.push(PackageChange::Removed {
+                name: name.clone(),
+                version: before_version.clone(),
+            }),
+            Some(after_version) if after_version != before_version => {
+                changes.push(PackageChange::Upgraded {
+                    name: name.clone(),
+                    from: before_version.clone(),
+                    to: after_version.clone(),
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
+    for (name, after_version) in &after.0 {
+        if !before.0.contains_key(name) {
+            changes.push(PackageChange::Added {
+                name: name.clone(),
+                version: after_version.clone(),
+            });
+        }
+    }
+
+    changes.sort_by(|a, b| change_name(a).cmp(change_name(b)));
+    changes
+}
+
+fn change_name(change: &PackageChange) -> &str {
+    match change {
+        PackageChange::Added { name, .. }
+        | PackageChange::Removed { name, .. }
+        | PackageChange::Upgraded { name, .. } => name,
+    }
+}
+
+/// Print a concise `+`/`-`/`~` changelog for `changes` through `printer`.
+///
+/// A single change collapses to one line (e.g. `+ requests==2.32.3`); a
+/// larger set is grouped into additions, removals, and upgrades so the
+/// common case stays a one-liner while bigger syncs stay scannable.
+pub fn print_diff_summary(printer: &Printer, changes: &[PackageChange]) {
+    if changes.is_empty() {
+        return;
+    }
+
+    if changes.len() == 1 {
+        printer.info(&format_change(&changes[0]));
+        return;
+    }
+
+    // Additions first, then removals, then upgrades; each group keeps the
+    // name order `diff_snapshots` produced.
+    for rank in 0..3 {
+        for change in changes.iter().filter(|change| change_rank(change) == rank) {
+            printer.info(&format_change(change));
+        }
+    }
+}
+
+fn change_rank(change: &PackageChange) -> u8 {
+    match change {
+        PackageChange::Added { .. } => 0,
+        PackageChange::Removed { .. } => 1,
+        PackageChange::Upgraded { .. } => 2,
+    }
+}
+
+fn format_change(change: &PackageChange) -> String {
+    match change {
+        PackageChange::Added { name, version } => format!("+ {name}=={version}"),
+        PackageChange::Removed { name, version } => format!("- {name}=={version}"),
+        PackageChange::Upgraded { name, from, to } => format!("~ {name} {from} -> {to}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(pairs: &[(&str, &str)]) -> PackageSnapshot {
+        PackageSnapshot(
+            pairs
+                .iter()
+                .map(|(n, v)| ((*n).to_owned(), (*v).to_owned()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn diff_detects_additions() {
+        let before = snapshot(&[]);
+        let after = snapshot(&[("requests", "2.32.3")]);
+
+        let changes = diff_snapshots(&before, &after);
+        assert_eq!(
+            changes,
+            vec![PackageChange::Added {
+                name: "requests".to_owned(),
+                version: "2.32.3".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_detects_removals() {
+        let before = snapshot(&[("requests", "2.32.3")]);
+        let after = snapshot(&[]);
+
+        let changes = diff_snapshots(&before, &after);
+        assert_eq!(
+            changes,
+            vec![PackageChange::Removed {
+                name: "requests".to_owned(),
+                version: "2.32.3".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_detects_upgrades() {
+        let before = snapshot(&[("requests", "2.31.0")]);
+        let after = snapshot(&[("requests", "2.32.3")]);
+
+        let changes = diff_snapshots(&before, &after);
+        assert_eq!(
+            changes,
+            vec![PackageChange::Upgraded {
+                name: "requests".to_owned(),
+                from: "2.31.0".to_owned(),
+                to: "2.32.3".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_ignores_unchanged_packages() {
+        let before = snapshot(&[("requests", "2.32.3")]);
+        let after = snapshot(&[("requests", "2.32.3")]);
+
+        assert!(diff_snapshots(&before, &after).is_empty());
+    }
+
+    #[test]
+    fn format_single_addition() {
+        let change = PackageChange::Added {
+            name: "requests".to_owned(),
+            version: "2.32.3".to_owned(),
+        };
+        assert_eq!(format_change(&change), "+ requests==2.32.3");
+    }
+}
@@ -0,0 +1,97 @@
+//! NDJSON event types for `--format json`.
+//!
+//! Each command that touches the lockfile or the environment builds one
+//! [`Event`] and hands it to [`Printer::emit_json`] instead of
+//! hand-rolling its own JSON shape, so `install`/`sync`'s package diffs
+//! and `update`'s lock diffs (including `--outdated`) share one
+//! serialization path rather than bespoke per-command formatting.
+
+use serde::Serialize;
+
+use crate::commands::diff::PackageChange;
+use crate::commands::update_plan::{Group, LockChange};
+
+/// One NDJSON line emitted on stdout under `--format json`.
+#[derive(Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    Install { changes: Vec<PackageChangeEvent> },
+    Sync { changes: Vec<PackageChangeEvent> },
+    Update { changes: Vec<LockChangeEvent>, synced: bool },
+    Outdated { changes: Vec<LockChangeEvent> },
+    Error { message: String },
+}
+
+/// JSON rendering of a [`PackageChange`] (installed package diffs).
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PackageChangeEvent {
+    Added { name: String, version: String },
+    Removed { name: String, version: String },
+    Upgraded { name: String, from: String, to: String },
+}
+
+impl From<&PackageChange> for PackageChangeEvent {
+    fn from(change: &PackageChange) -> Self {
+        match change {
+            PackageChange::Added { name, version } => Self::Added {
+                name: name.clone(),
+                version: version.clone(),
+            },
+            PackageChange::Removed { name, version } => Self::Removed {
+                name: name.clone(),
+                version: version.clone(),
+            },
+            PackageChange::Upgraded { name, from, to } => Self::Upgraded {
+                name: name.clone(),
+                from: from.clone(),
+                to: to.clone(),
+            },
+        }
+    }
+}
+
+/// JSON rendering of a `(Group, LockChange)` pair (lockfile diffs).
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LockChangeEvent {
+    Added { group: &'static str, name: String, version: String },
+    Removed { group: &'static str, name: String, version: String },
+    Upgraded { group: &'static str, name: String, from: String, to: String },
+    Downgraded { group: &'static str, name: String, from: String, to: String },
+    Changed { group: &'static str, name: String },
+}
+
+impl From<&(Group, LockChange)> for LockChangeEvent {
+    fn from((group, change): &(Group, LockChange)) -> Self {
+        let group = group.label();
+        match change {
+            LockChange::Added { name, version } => Self::Added {
+                group,
+                name: name.clone(),
+                version: version.clone(),
+            },
+            LockChange::Removed { name, version } => Self::Removed {
+                group,
+                name: name.clone(),
+                version: version.clone(),
+            },
+            LockChange::Upgraded { name, from, to } => Self::Upgraded {
+                group,
+                name: name.clone(),
+                from: from.clone(),
+                to: to.clone(),
+            },
+            LockChange::Downgraded { name, from, to } => Self::Downgraded {
+                group,
+                name: name.clone(),
+                from: from.clone(),
+                to: to.clone(),
+            },
+            LockChange::Changed { name } => Self::Changed {
+                group,
+                name: name.clone(),
+            },
+        }
+    }
+}
@@ -0,0 +1,293 @@
+//! `ripenv global` — standalone CLI tools in isolated per-tool venvs,
+//! tracked in `~/.config/ripenv/global.toml` rather than any project's
+//! Pipfile.
+//!
+//! Unlike every other command here, this one has no project to discover:
+//! there's no [`UvContext`](crate::commands::uv_runner::UvContext), no
+//! Pipfile, no `cwd`-relative lockfile. The manifest (see
+//! [`crate::global::manifest`]) is the source of truth instead, and each
+//! subcommand reconciles a dedicated venv under
+//! `GlobalManifest::venv_dir` against it.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use crate::cli::{GlobalArgs, GlobalCommand, GlobalInstallArgs, GlobalRemoveArgs, GlobalSyncArgs};
+use crate::commands::ExitStatus;
+use crate::commands::install::parse_requirement_line;
+use crate::global::GlobalManifest;
+use crate::global::manifest::GlobalTool;
+use crate::pipfile::model::PipfilePackage;
+use crate::printer::Printer;
+
+/// File dropped alongside a tool's venv recording the spec it was last
+/// installed with, so `global sync` can tell a stale venv (the manifest's
+/// spec has since changed) from an up-to-date one without re-resolving
+/// every time.
+const SPEC_MARKER_FILE: &str = ".ripenv-spec";
+
+/// Execute `ripenv global`.
+pub async fn execute(
+    args: &GlobalArgs,
+    printer: Printer,
+    verbosity: u8,
+    quiet: bool,
+) -> Result<ExitStatus> {
+    match &args.command {
+        GlobalCommand::Install(install_args) => install(install_args, printer).await,
+        GlobalCommand::Remove(remove_args) => remove(remove_args, printer).await,
+        GlobalCommand::List(_) => list(printer),
+        GlobalCommand::Sync(sync_args) => sync(sync_args, printer, verbosity, quiet).await,
+    }
+}
+
+async fn install(args: &GlobalInstallArgs, printer: Printer) -> Result<ExitStatus> {
+    let mut manifest = GlobalManifest::load()?;
+    let (name, package) = parse_requirement_line(&args.package, None);
+
+    if manifest.tools.contains_key(&name) && !args.force {
+        printer.info(&format!(
+            "'{name}' is already installed globally. Pass --force to reinstall."
+        ));
+        return Ok(ExitStatus::Success);
+    }
+
+    let venv_dir = GlobalManifest::venv_dir(&name)?;
+    install_into_venv(&name, &package, &venv_dir, args.pre).await?;
+    write_installed_spec(&venv_dir, version_spec(&package))?;
+
+    manifest.tools.insert(
+        name.clone(),
+        GlobalTool {
+            packages: std::collections::BTreeMap::from([(name.clone(), package)]),
+        },
+    );
+    manifest.save()?;
+
+    printer.info(&format!("Installed '{name}' into {}", venv_dir.display()));
+    Ok(ExitStatus::Success)
+}
+
+async fn remove(args: &GlobalRemoveArgs, printer: Printer) -> Result<ExitStatus> {
+    let mut manifest = GlobalManifest::load()?;
+
+    if manifest.tools.remove(&args.tool).is_none() {
+        printer.warn(&format!(
+            "'{}' is not installed globally (checked global.toml).",
+            args.tool
+        ));
+        return Ok(ExitStatus::Failure);
+    }
+    manifest.save()?;
+
+    let venv_dir = GlobalManifest::venv_dir(&args.tool)?;
+    if venv_dir.is_dir() {
+        fs_err::remove_dir_all(&venv_dir)?;
+    }
+
+    printer.info(&format!("Removed '{}'.", args.tool));
+    Ok(ExitStatus::Success)
+}
+
+fn list(printer: Printer) -> Result<ExitStatus> {
+    let manifest = GlobalManifest::load()?;
+
+    if manifest.tools.is_empty() {
+        printer.info("No tools installed globally.");
+        return Ok(ExitStatus::Success);
+    }
+
+    for (name, tool) in &manifest.tools {
+        let spec = tool.packages.get(name).map_or("*", |pkg| version_spec(pkg));
+        printer.info(&format!("{name} {spec}"));
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// `ripenv global sync` — reconcile installed tool venvs against
+/// `global.toml`: install what's missing, remove what's no longer
+/// listed, and reinstall anything whose recorded spec has drifted from
+/// the manifest.
+async fn sync(
+    args: &GlobalSyncArgs,
+    printer: Printer,
+    _verbosity: u8,
+    _quiet: bool,
+) -> Result<ExitStatus> {
+    let manifest = GlobalManifest::load()?;
+
+    for (name, venv_dir) in orphaned_venvs(&manifest)? {
+        if args.dry_run {
+            printer.info(&format!("Would remove '{name}' (no longer in global.toml)."));
+            continue;
+        }
+        fs_err::remove_dir_all(&venv_dir)?;
+        printer.info(&format!("Removed '{name}' (no longer in global.toml)."));
+    }
+
+    for (name, tool) in &manifest.tools {
+        let venv_dir = GlobalManifest::venv_dir(name)?;
+        let Some(package) = tool.packages.get(name) else {
+            continue;
+        };
+        let spec = version_spec(package);
+
+        if !venv_dir.is_dir() {
+            if args.dry_run {
+                printer.info(&format!("Would install '{name}' into {}", venv_dir.display()));
+                continue;
+            }
+            install_into_venv(name, package, &venv_dir, false).await?;
+            write_installed_spec(&venv_dir, spec)?;
+            printer.info(&format!("Installed '{name}' into {}", venv_dir.display()));
+            continue;
+        }
+
+        if needs_reinstall(&venv_dir, spec) {
+            if args.dry_run {
+                printer.info(&format!("Would upgrade '{name}' to match global.toml ({spec})."));
+                continue;
+            }
+            install_into_venv(name, package, &venv_dir, false).await?;
+            write_installed_spec(&venv_dir, spec)?;
+            printer.info(&format!("Upgraded '{name}' to {spec}."));
+        }
+    }
+
+    if !args.dry_run {
+        printer.info("Global tools are in sync with global.toml.");
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Venvs present under [`GlobalManifest::venvs_dir`] that no longer
+/// correspond to any tool in `manifest`, paired with their directory.
+fn orphaned_venvs(manifest: &GlobalManifest) -> Result<Vec<(String, PathBuf)>> {
+    orphans_in(&GlobalManifest::venvs_dir()?, manifest)
+}
+
+fn orphans_in(venvs_dir: &Path, manifest: &GlobalManifest) -> Result<Vec<(String, PathBuf)>> {
+    if !venvs_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut orphans = Vec::new();
+    for entry in fs_err::read_dir(venvs_dir)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !manifest.tools.contains_key(&name) {
+            orphans.push((name, entry.path()));
+        }
+    }
+    orphans.sort();
+    Ok(orphans)
+}
+
+/// Whether `venv_dir`'s recorded spec (see [`SPEC_MARKER_FILE`]) no
+/// longer matches `spec`, meaning the manifest has moved on and the venv
+/// needs reinstalling to catch up.
+fn needs_reinstall(venv_dir: &Path, spec: &str) -> bool {
+    read_installed_spec(venv_dir).as_deref() != Some(spec)
+}
+
+fn read_installed_spec(venv_dir: &Path) -> Option<String> {
+    fs_err::read_to_string(venv_dir.join(SPEC_MARKER_FILE)).ok()
+}
+
+fn write_installed_spec(venv_dir: &Path, spec: &str) -> Result<()> {
+    fs_err::write(venv_dir.join(SPEC_MARKER_FILE), spec)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::global::manifest::GlobalTool;
+
+    fn manifest_with(tools: &[&str]) -> GlobalManifest {
+        let mut manifest = GlobalManifest::default();
+        for &name in tools {
+            manifest.tools.insert(
+                name.to_owned(),
+                GlobalTool {
+                    packages: BTreeMap::from([(
+                        name.to_owned(),
+                        PipfilePackage::Simple(">=24".to_owned()),
+                    )]),
+                },
+            );
+        }
+        manifest
+    }
+
+    #[test]
+    fn orphans_in_finds_venvs_missing_from_manifest() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs_err::create_dir_all(dir.path().join("black")).unwrap();
+        fs_err::create_dir_all(dir.path().join("ruff")).unwrap();
+
+        let manifest = manifest_with(&["black"]);
+        let orphans = orphans_in(dir.path(), &manifest).unwrap();
+
+        assert_eq!(orphans, vec![("ruff".to_owned(), dir.path().join("ruff"))]);
+    }
+
+    #[test]
+    fn orphans_in_missing_dir_is_empty() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let manifest = manifest_with(&["black"]);
+        let orphans = orphans_in(&dir.path().join("does-not-exist"), &manifest).unwrap();
+        assert!(orphans.is_empty());
+    }
+
+    #[test]
+    fn needs_reinstall_when_spec_changed() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs_err::create_dir_all(dir.path()).unwrap();
+        write_installed_spec(dir.path(), ">=24").unwrap();
+
+        assert!(!needs_reinstall(dir.path(), ">=24"));
+        assert!(needs_reinstall(dir.path(), ">=25"));
+    }
+
+    #[test]
+    fn needs_reinstall_when_marker_missing() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(needs_reinstall(dir.path(), ">=24"));
+    }
+}
+
+/// Resolve and install `package` into a fresh venv at `venv_dir`, via uv's
+/// own tool-install machinery (the same primitives behind `uv tool
+/// install`), and link its entry-point scripts onto `PATH`.
+async fn install_into_venv(
+    name: &str,
+    package: &PipfilePackage,
+    venv_dir: &std::path::Path,
+    pre: bool,
+) -> Result<()> {
+    let requirement = match version_spec(package) {
+        "*" | "" => name.to_owned(),
+        version => format!("{name}{version}"),
+    };
+    uv::commands::tool::install(name, &requirement, venv_dir, pre).await?;
+    Ok(())
+}
+
+/// The version specifier portion of a [`PipfilePackage`] (e.g. `"*"`,
+/// `">=24"`), for display in `ripenv global list` and to build a uv-style
+/// requirement string for installation.
+fn version_spec(package: &PipfilePackage) -> &str {
+    match package {
+        PipfilePackage::Simple(version) => version,
+        PipfilePackage::Detailed(detail) => detail.version.as_deref().unwrap_or("*"),
+    }
+}
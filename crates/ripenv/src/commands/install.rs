@@ -4,18 +4,19 @@
 //! - No packages: equivalent to `ripenv sync` (install from lockfile).
 //! - With packages: add to Pipfile, then lock + sync.
 
-use anyhow::{Result, bail};
-use uv_cache::{Cache, Refresh};
-use uv_cli::SyncFormat;
-use uv_configuration::{
-    DependencyGroups, DryRun, EditableMode, ExtrasSpecification, InstallOptions,
-};
-use uv_resolver::PrereleaseMode;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use uv_configuration::{DependencyGroups, Upgrade};
 
 use crate::cli::InstallArgs;
 use crate::commands::ExitStatus;
+use crate::commands::diff::{PackageSnapshot, diff_snapshots, print_diff_summary};
+use crate::commands::events::Event;
+use crate::commands::project_ops::{LockOptions, SyncOptions};
 use crate::commands::uv_runner::UvContext;
-use crate::pipfile::model::{PipfilePackage, PipfilePackageDetail};
+use crate::pipfile::model::{Pipfile, PipfilePackage, PipfilePackageDetail, PipfileSource};
 use crate::printer::Printer;
 
 /// Execute `ripenv install`.
@@ -34,31 +35,16 @@ pub async fn execute(
 
 async fn do_lock(
     ctx: &UvContext,
-    cache: &Cache,
     lock_check: uv::settings::LockCheck,
+    upgrade: Upgrade,
 ) -> Result<ExitStatus> {
-    let lock_exit_status = uv::commands::project::lock::lock(
-        &ctx.project_dir,
-        lock_check,
-        None, // frozen
-        DryRun::default(),
-        Refresh::from_args(None, vec![]),
-        None, // python
-        ctx.install_mirrors(),
-        ctx.resolver_settings(),
-        ctx.client_builder(),
-        None, // script
-        ctx.python_preference(),
-        ctx.python_downloads(),
-        ctx.concurrency(),
-        false, // no_config
-        cache,
-        ctx.uv_printer(),
-        ctx.preview(),
-    )
-    .await?;
-
-    Ok(lock_exit_status)
+    ctx.project_ops()
+        .lock(LockOptions {
+            check: lock_check,
+            upgrade,
+            ..LockOptions::default()
+        })
+        .await
 }
 
 /// `ripenv install` with no packages — sync from the lockfile.
@@ -72,11 +58,10 @@ async fn install_from_lockfile(
 
     // If --deploy, verify the lockfile is up to date first
     if args.deploy {
-        let cache = ctx.cache()?;
         let check_result = do_lock(
             &ctx,
-            &cache,
             uv::settings::LockCheck::Enabled(uv::settings::LockCheckSource::Check),
+            Upgrade::None,
         )
         .await?;
 
@@ -97,46 +82,36 @@ async fn install_from_lockfile(
     );
 
     let python_preference = if args.system {
-        uv_python::PythonPreference::System
+        Some(uv_python::PythonPreference::System)
     } else {
-        ctx.python_preference()
+        None
     };
 
-    let cache = ctx.cache()?;
-
-    let result = Box::pin(uv::commands::project::sync::sync(
-        &ctx.project_dir,
-        uv::settings::LockCheck::Disabled,
-        None, // frozen
-        DryRun::default(),
-        None,   // active
-        false,  // all_packages
-        vec![], // package
-        ExtrasSpecification::default(),
-        groups,
-        Some(EditableMode::default()),
-        InstallOptions::default(),
-        uv::commands::pip::operations::Modifications::Exact,
-        None, // python
-        None, // python_platform
-        ctx.install_mirrors(),
-        python_preference,
-        ctx.python_downloads(),
-        ctx.resolver_installer_settings(),
-        ctx.client_builder(),
-        None,  // script
-        false, // installer_metadata
-        ctx.concurrency(),
-        false, // no_config
-        &cache,
-        ctx.uv_printer(),
-        ctx.preview(),
-        SyncFormat::default(),
-    ))
-    .await?;
+    let before = ctx
+        .environment()
+        .ok()
+        .and_then(|env| PackageSnapshot::capture(&env).ok());
+
+    let result = ctx
+        .project_ops()
+        .sync(SyncOptions {
+            groups,
+            python_preference,
+            python_request: None,
+        })
+        .await?;
 
     if matches!(result, ExitStatus::Success) {
-        ctx.generate_pipfile_lock()?;
+        ctx.generate_pipfile_lock(None, false)?;
+        if let (Some(before), Ok(env)) = (before, ctx.environment()) {
+            if let Ok(after) = PackageSnapshot::capture(&env) {
+                let changes = diff_snapshots(&before, &after);
+                print_diff_summary(&ctx.printer, &changes);
+                ctx.printer.emit_json(&Event::Install {
+                    changes: changes.iter().map(Into::into).collect(),
+                });
+            }
+        }
         ctx.printer.info("Install complete.");
     }
 
@@ -152,10 +127,30 @@ async fn install_packages(
 ) -> Result<ExitStatus> {
     let mut ctx = UvContext::discover_or_init(printer, verbosity, quiet)?;
 
+    // Track which sources and package keys this run actually touches, so
+    // the Pipfile write below can surgically edit just those instead of
+    // regenerating the whole file.
+    let existing_source_urls: std::collections::HashSet<String> =
+        ctx.pipfile.source.iter().map(|s| s.url.clone()).collect();
+    let mut touched_packages: Vec<(String, bool)> = Vec::new();
+
+    // Register --index-url/--extra-index-url as `[[source]]` entries before
+    // parsing specs, so packages can reference the index by name. Only the
+    // primary `--index-url` is assigned to newly-added packages; extras are
+    // made available to the resolver without changing anyone's pin.
+    let index_name = args
+        .index
+        .as_deref()
+        .map(|url| ensure_source(&mut ctx.pipfile, url));
+    for url in &args.extra_index_url {
+        ensure_source(&mut ctx.pipfile, url);
+    }
+
     // Parse and add each package to the Pipfile
     for spec in &args.packages {
-        let (name, package) = parse_package_spec(spec, args);
+        let (name, package) = parse_package_spec(spec, args, index_name.as_deref());
 
+        touched_packages.push((name.clone(), args.dev_packages));
         if args.dev_packages {
             ctx.pipfile.dev_packages.insert(name, package);
         } else {
@@ -165,52 +160,46 @@ async fn install_packages(
 
     // Handle -r requirements.txt
     if let Some(ref req_file) = args.requirements {
-        let content = fs_err::read_to_string(req_file)?;
-        for line in content.lines() {
-            let line = line.trim();
-            if line.is_empty() || line.starts_with('#') || line.starts_with('-') {
-                continue;
-            }
-            let (name, package) = parse_requirement_line(line);
-            ctx.pipfile.packages.insert(name, package);
-        }
+        let mut file_index_name = index_name.clone();
+        import_requirements(
+            &mut ctx.pipfile,
+            Path::new(req_file),
+            &mut file_index_name,
+            &mut HashSet::new(),
+            &mut touched_packages,
+            false,
+        )?;
     }
 
-    // Write updated Pipfile
-    ctx.pipfile.write_to(&ctx.pipfile_path)?;
+    // Write the updated Pipfile. Surgically edit just the sources and
+    // package keys this run touched, preserving comments, ordering, and
+    // inline-table style for everything else; fall back to a full rewrite
+    // only for the auto-create case, where there's nothing yet to preserve.
+    let new_sources: Vec<&PipfileSource> = ctx
+        .pipfile
+        .source
+        .iter()
+        .filter(|source| !existing_source_urls.contains(&source.url))
+        .collect();
+    ctx.pipfile
+        .apply_edits(&ctx.pipfile_path, &new_sources, &touched_packages)?;
 
     // Regenerate virtual pyproject.toml
     ctx.refresh()?;
 
-    let cache = ctx.cache()?;
-
     // Lock (unless --skip-lock)
     if !args.skip_lock {
-        let mut settings = ctx.resolver_settings();
-        if args.pre {
-            settings.prerelease = PrereleaseMode::Allow;
+        let mut lock_options = LockOptions {
+            allow_prereleases: args.pre,
+            ..LockOptions::default()
+        };
+        if args.keep_outdated {
+            // Only the packages just added to the Pipfile need resolving;
+            // every other already-locked pin stays exactly where it is.
+            lock_options.upgrade = Upgrade::None;
         }
 
-        let result = uv::commands::project::lock::lock(
-            &ctx.project_dir,
-            uv::settings::LockCheck::Disabled,
-            None, // frozen
-            DryRun::default(),
-            Refresh::from_args(None, vec![]),
-            None, // python
-            ctx.install_mirrors(),
-            settings,
-            ctx.client_builder(),
-            None, // script
-            ctx.python_preference(),
-            ctx.python_downloads(),
-            ctx.concurrency(),
-            false, // no_config
-            &cache,
-            ctx.uv_printer(),
-            ctx.preview(),
-        )
-        .await?;
+        let result = ctx.project_ops().lock(lock_options).await?;
 
         if !matches!(result, ExitStatus::Success) {
             return Ok(result);
@@ -229,47 +218,232 @@ async fn install_packages(
         false,       // all_groups
     );
 
-    let result = Box::pin(uv::commands::project::sync::sync(
-        &ctx.project_dir,
-        uv::settings::LockCheck::Disabled,
-        None, // frozen
-        DryRun::default(),
-        None,   // active
-        false,  // all_packages
-        vec![], // package
-        ExtrasSpecification::default(),
-        groups,
-        Some(EditableMode::default()),
-        InstallOptions::default(),
-        uv::commands::pip::operations::Modifications::Exact,
-        None, // python
-        None, // python_platform
-        ctx.install_mirrors(),
-        ctx.python_preference(),
-        ctx.python_downloads(),
-        ctx.resolver_installer_settings(),
-        ctx.client_builder(),
-        None,  // script
-        false, // installer_metadata
-        ctx.concurrency(),
-        false, // no_config
-        &cache,
-        ctx.uv_printer(),
-        ctx.preview(),
-        SyncFormat::default(),
-    ))
-    .await?;
+    let before = ctx
+        .environment()
+        .ok()
+        .and_then(|env| PackageSnapshot::capture(&env).ok());
+
+    let result = ctx
+        .project_ops()
+        .sync(SyncOptions {
+            groups,
+            python_preference: None,
+            python_request: None,
+        })
+        .await?;
 
     if matches!(result, ExitStatus::Success) {
-        ctx.generate_pipfile_lock()?;
+        if let (Some(before), Ok(env)) = (before, ctx.environment()) {
+            if let Ok(after) = PackageSnapshot::capture(&env) {
+                let changes = diff_snapshots(&before, &after);
+                print_diff_summary(&ctx.printer, &changes);
+                ctx.printer.emit_json(&Event::Install {
+                    changes: changes.iter().map(Into::into).collect(),
+                });
+            }
+        }
         ctx.printer.info("Install complete.");
     }
 
     Ok(result)
 }
 
+/// Import a requirements.txt file into `pipfile`, following any `-r`/
+/// `--requirement` or `-c`/`--constraint` includes it names (resolved
+/// relative to the including file) recursively, registering `-i`/
+/// `--index-url`/`--extra-index-url` directives as `[[source]]` entries
+/// as they're encountered, and routing `-e`/`--editable` entries through
+/// the editable path instead of `parse_requirement_line`.
+///
+/// `index_name` carries the active index across the whole include tree
+/// (an `-i` line in an included file applies to every package parsed
+/// afterward, in that file and its own includes), and `visited` guards
+/// against include cycles by canonical path.
+fn import_requirements(
+    pipfile: &mut Pipfile,
+    path: &Path,
+    index_name: &mut Option<String>,
+    visited: &mut HashSet<PathBuf>,
+    touched: &mut Vec<(String, bool)>,
+    is_constraints: bool,
+) -> Result<()> {
+    let canonical = fs_err::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    if !visited.insert(canonical) {
+        return Ok(());
+    }
+
+    let content = fs_err::read_to_string(path)
+        .with_context(|| format!("failed to read requirements file {}", path.display()))?;
+    let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+    for line in join_line_continuations(&content) {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(url) = strip_flag_value(line, &["-i", "--index-url"]) {
+            *index_name = Some(ensure_source(pipfile, url));
+            continue;
+        }
+        if let Some(url) = strip_flag_value(line, &["--extra-index-url"]) {
+            ensure_source(pipfile, url);
+            continue;
+        }
+        if let Some(include) = strip_flag_value(line, &["-r", "--requirement"]) {
+            import_requirements(
+                pipfile,
+                &dir.join(include),
+                index_name,
+                visited,
+                touched,
+                is_constraints,
+            )?;
+            continue;
+        }
+        if let Some(include) = strip_flag_value(line, &["-c", "--constraint"]) {
+            // A constraint file only bounds versions of packages required
+            // elsewhere; everything it includes (directly or transitively)
+            // stays in constraints mode too.
+            import_requirements(pipfile, &dir.join(include), index_name, visited, touched, true)?;
+            continue;
+        }
+        if let Some(spec) = strip_flag_value(line, &["-e", "--editable"]) {
+            if is_constraints {
+                // Constraint files can't pin editable/VCS installs; skip
+                // rather than misapplying one as a new dependency.
+                continue;
+            }
+            let (name, package) = parse_editable_spec(spec);
+            touched.push((name.clone(), false));
+            pipfile.packages.insert(name, package);
+            continue;
+        }
+        if line.starts_with('-') {
+            // Any other pip option (--no-binary, --pre, etc.) has no
+            // Pipfile equivalent; skip it rather than misparsing it as a
+            // package name.
+            continue;
+        }
+
+        let (name, package) = parse_requirement_line(line, index_name.as_deref());
+        if is_constraints {
+            apply_constraint(pipfile, &name, package);
+        } else {
+            touched.push((name.clone(), false));
+            pipfile.packages.insert(name, package);
+        }
+    }
+
+    Ok(())
+}
+
+/// Apply a `-c`/`--constraint` line to whichever Pipfile section already
+/// depends on `name`, updating its version bound. Constraint files never
+/// introduce a new top-level dependency — a constraint on a package
+/// nothing else requires is simply a no-op, matching pip's `-c` semantics.
+///
+/// Requirements are applied in file order rather than pip's two-pass
+/// resolution, so a `-c` include only bounds requirement lines that
+/// precede it.
+fn apply_constraint(pipfile: &mut Pipfile, name: &str, constraint: PipfilePackage) {
+    let version = match &constraint {
+        PipfilePackage::Simple(version) => version.clone(),
+        PipfilePackage::Detailed(detail) => {
+            detail.version.clone().unwrap_or_else(|| "*".to_owned())
+        }
+    };
+
+    for existing in pipfile
+        .packages
+        .get_mut(name)
+        .into_iter()
+        .chain(pipfile.dev_packages.get_mut(name))
+    {
+        match existing {
+            PipfilePackage::Simple(v) => *v = version.clone(),
+            PipfilePackage::Detailed(detail) => detail.version = Some(version.clone()),
+        }
+    }
+}
+
+/// Join backslash-continued requirements.txt lines into single logical
+/// lines, so a `--hash=` list spread across several lines parses as one
+/// entry instead of being cut off at the first line break.
+fn join_line_continuations(content: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for raw in content.lines() {
+        let line = raw.trim_end();
+        // Drop a continuation line's indentation so pieces join with one space.
+        let line = if current.is_empty() { line } else { line.trim_start() };
+        match line.strip_suffix('\\') {
+            Some(stripped) => {
+                current.push_str(stripped.trim_end());
+                current.push(' ');
+            }
+            None => {
+                current.push_str(line);
+                lines.push(std::mem::take(&mut current));
+            }
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Parse a `-e`/`--editable` requirements-file entry into a Pipfile path
+/// entry, the same way `parse_package_spec`'s `--editable` flag does: the
+/// whole spec becomes the path, with its name derived from the portion
+/// before any version operator.
+fn parse_editable_spec(spec: &str) -> (String, PipfilePackage) {
+    let (name, _) = split_name_version(spec);
+    (
+        name.to_owned(),
+        PipfilePackage::Detailed(PipfilePackageDetail {
+            path: Some(spec.to_owned()),
+            editable: true,
+            ..PipfilePackageDetail::default()
+        }),
+    )
+}
+
+/// Split `name[extra1,extra2]>=1.0` into its name, extras, and version
+/// parts. Falls back to [`split_name_version`] when there's no `[...]`
+/// extras suffix.
+fn split_name_extras_version(spec: &str) -> (&str, Vec<String>, &str) {
+    if let Some(start) = spec.find('[') {
+        if let Some(len) = spec[start..].find(']') {
+            let end = start + len;
+            let extras = spec[start + 1..end]
+                .split(',')
+                .map(str::trim)
+                .filter(|extra| !extra.is_empty())
+                .map(str::to_owned)
+                .collect();
+            return (&spec[..start], extras, &spec[end + 1..]);
+        }
+    }
+    let (name, version) = split_name_version(spec);
+    (name, Vec::new(), version)
+}
+
 /// Parse a package spec string like `"requests"`, `"requests>=2.0"`, or `"requests[security]"`.
-fn parse_package_spec(spec: &str, args: &InstallArgs) -> (String, PipfilePackage) {
+///
+/// `index_name` is the `[[source]]` name to pin this package to, if
+/// `--index-url` (or a requirements-file `-i` line) selected one; VCS and
+/// editable specs ignore it, since an index has no meaning for them.
+fn parse_package_spec(
+    spec: &str,
+    args: &InstallArgs,
+    index_name: Option<&str>,
+) -> (String, PipfilePackage) {
+    if let Some((name, detail)) = parse_git_spec(spec) {
+        return (name, PipfilePackage::Detailed(detail));
+    }
+
     let (name, version) = split_name_version(spec);
 
     if args.editable {
@@ -283,10 +457,18 @@ fn parse_package_spec(spec: &str, args: &InstallArgs) -> (String, PipfilePackage
         );
     }
 
-    if version.is_empty() {
-        (name.to_owned(), PipfilePackage::Simple("*".to_owned()))
-    } else {
-        (name.to_owned(), PipfilePackage::Simple(version.to_owned()))
+    let version = if version.is_empty() { "*" } else { version };
+
+    match index_name {
+        Some(index) => (
+            name.to_owned(),
+            PipfilePackage::Detailed(PipfilePackageDetail {
+                version: Some(version.to_owned()),
+                index: Some(index.to_owned()),
+                ..PipfilePackageDetail::default()
+            }),
+        ),
+        None => (name.to_owned(), PipfilePackage::Simple(version.to_owned())),
     }
 }
 
@@ -304,12 +486,452 @@ fn split_name_version(spec: &str) -> (&str, &str) {
     (spec, "")
 }
 
-/// Parse a requirements.txt line into a Pipfile package entry.
-fn parse_requirement_line(line: &str) -> (String, PipfilePackage) {
-    let (name, version) = split_name_version(line);
-    if version.is_empty() {
-        (name.to_owned(), PipfilePackage::Simple("*".to_owned()))
-    } else {
-        (name.to_owned(), PipfilePackage::Simple(version.to_owned()))
+/// Parse a requirements.txt line (or a bare `ripenv global install` spec)
+/// into a Pipfile-style package entry, capturing `name[extras]`,
+/// trailing `; markers`, and any `--hash=` values along the way.
+///
+/// `index_name` is threaded through from whichever `-i`/`--index-url` line
+/// (if any) preceded this one in the file, or from `--index-url` on the
+/// command line. Produces a `Simple` entry when nothing but a version
+/// pin was present, and a `Detailed` one as soon as extras, markers,
+/// hashes, or an index are involved.
+pub(crate) fn parse_requirement_line(
+    line: &str,
+    index_name: Option<&str>,
+) -> (String, PipfilePackage) {
+    if let Some((name, detail)) = parse_git_spec(line) {
+        return (name, PipfilePackage::Detailed(detail));
+    }
+
+    let mut hashes = Vec::new();
+    let without_hashes: Vec<&str> = line
+        .split_whitespace()
+        .filter_map(|token| match token.strip_prefix("--hash=") {
+            Some(hash) => {
+                hashes.push(hash.to_owned());
+                None
+            }
+            None => Some(token),
+        })
+        .collect();
+    let without_hashes = without_hashes.join(" ");
+
+    let (spec, markers) = match without_hashes.split_once(';') {
+        Some((spec, markers)) => (spec.trim(), Some(markers.trim().to_owned())),
+        None => (without_hashes.trim(), None),
+    };
+
+    let (name, extras, version) = split_name_extras_version(spec);
+    let version = if version.is_empty() { "*" } else { version };
+
+    if extras.is_empty() && markers.is_none() && hashes.is_empty() && index_name.is_none() {
+        return (name.to_owned(), PipfilePackage::Simple(version.to_owned()));
+    }
+
+    (
+        name.to_owned(),
+        PipfilePackage::Detailed(PipfilePackageDetail {
+            version: Some(version.to_owned()),
+            extras,
+            markers,
+            index: index_name.map(str::to_owned),
+            hashes,
+            ..PipfilePackageDetail::default()
+        }),
+    )
+}
+
+/// Parse a requirements-file directive line with a flag-value argument
+/// (`-i URL`, `--index-url URL`, `-r file.txt`, ...) and return the value
+/// if `line` matches one of `flags`.
+fn strip_flag_value<'a>(line: &'a str, flags: &[&str]) -> Option<&'a str> {
+    for flag in flags {
+        if let Some(rest) = line.strip_prefix(flag) {
+            let rest = rest.trim();
+            if let Some(url) = rest.strip_prefix('=') {
+                return Some(url.trim());
+            }
+            if !rest.is_empty() && rest != line {
+                return Some(rest);
+            }
+        }
+    }
+    None
+}
+
+/// Ensure a `[[source]]` entry exists for `url` in the Pipfile, adding one
+/// with an auto-derived name if it doesn't, and return its name.
+///
+/// Deduplicates by URL so repeating `--extra-index-url` (or re-running
+/// `install` against the same requirements file) doesn't pile up identical
+/// sources.
+fn ensure_source(pipfile: &mut Pipfile, url: &str) -> String {
+    if let Some(existing) = pipfile.source.iter().find(|source| source.url == url) {
+        return existing.name.clone();
+    }
+
+    let name = source_name_for_url(url, &pipfile.source);
+    pipfile.source.push(PipfileSource {
+        name: name.clone(),
+        url: url.to_owned(),
+        verify_ssl: true,
+    });
+    name
+}
+
+/// Derive a short, unique source name from an index URL's host, e.g.
+/// `https://pypi.example.com/simple` -> `pypi-example-com`. Falls back to
+/// `extra-index` (suffixed if needed) for URLs with no parseable host.
+fn source_name_for_url(url: &str, existing: &[PipfileSource]) -> String {
+    let host = url
+        .split("://")
+        .nth(1)
+        .and_then(|rest| rest.split('/').next())
+        .map(|host| host.rsplit('@').next().unwrap_or(host))
+        .map(|host| host.replace(['.', ':'], "-"));
+
+    let base = host.unwrap_or_else(|| "extra-index".to_owned());
+    if !existing.iter().any(|source| source.name == base) {
+        return base;
+    }
+
+    (2..)
+        .map(|n| format!("{base}-{n}"))
+        .find(|candidate| !existing.iter().any(|source| &source.name == candidate))
+        .expect("infinite iterator always yields an unused name")
+}
+
+/// Parse a pip-style VCS spec, e.g.
+/// `git+https://github.com/org/repo@<ref>#egg=<name>` or
+/// `git+ssh://git@host/org/repo.git@<ref>#egg=<name>&subdirectory=<path>`.
+///
+/// Returns `None` for anything not prefixed with `git+`, leaving ordinary
+/// version specs and local paths to the existing parsing path.
+fn parse_git_spec(spec: &str) -> Option<(String, PipfilePackageDetail)> {
+    let url = spec.strip_prefix("git+")?;
+
+    let (base, fragment) = match url.split_once('#') {
+        Some((base, fragment)) => (base, Some(fragment)),
+        None => (url, None),
+    };
+    let (repo, git_ref) = match base.rsplit_once('@') {
+        Some((repo, git_ref)) => (repo, Some(git_ref.to_owned())),
+        None => (base, None),
+    };
+
+    let mut egg = None;
+    let mut subdirectory = None;
+    for part in fragment.into_iter().flat_map(|fragment| fragment.split('&')) {
+        if let Some(value) = part.strip_prefix("egg=") {
+            egg = Some(value.to_owned());
+        } else if let Some(value) = part.strip_prefix("subdirectory=") {
+            subdirectory = Some(value.to_owned());
+        }
+    }
+
+    let name = egg.unwrap_or_else(|| {
+        repo.rsplit('/')
+            .next()
+            .unwrap_or(repo)
+            .trim_end_matches(".git")
+            .to_owned()
+    });
+
+    Some((
+        name,
+        PipfilePackageDetail {
+            git: Some(repo.to_owned()),
+            git_ref,
+            subdirectory,
+            ..PipfilePackageDetail::default()
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn git_spec_with_egg_and_ref() {
+        let (name, detail) =
+            parse_git_spec("git+https://github.com/example/my-pkg.git@v1.2.3#egg=my-pkg")
+                .expect("should parse git+ spec");
+
+        assert_eq!(name, "my-pkg");
+        assert_eq!(
+            detail.git.as_deref(),
+            Some("https://github.com/example/my-pkg.git")
+        );
+        assert_eq!(detail.git_ref.as_deref(), Some("v1.2.3"));
+        assert_eq!(detail.subdirectory, None);
+    }
+
+    #[test]
+    fn git_spec_with_subdirectory() {
+        let (name, detail) = parse_git_spec(
+            "git+ssh://git@github.com/example/monorepo.git@main#egg=sub-pkg&subdirectory=packages/sub-pkg",
+        )
+        .expect("should parse git+ spec");
+
+        assert_eq!(name, "sub-pkg");
+        assert_eq!(detail.git_ref.as_deref(), Some("main"));
+        assert_eq!(detail.subdirectory.as_deref(), Some("packages/sub-pkg"));
+    }
+
+    #[test]
+    fn git_spec_without_egg_infers_name_from_repo() {
+        let (name, detail) = parse_git_spec("git+https://github.com/example/my-pkg.git@main")
+            .expect("should parse git+ spec");
+
+        assert_eq!(name, "my-pkg");
+        assert_eq!(detail.git_ref.as_deref(), Some("main"));
+    }
+
+    #[test]
+    fn non_git_spec_returns_none() {
+        assert!(parse_git_spec("requests>=2.0").is_none());
+        assert!(parse_git_spec("./local-pkg").is_none());
+    }
+
+    #[test]
+    fn strip_flag_value_recognizes_short_and_long_forms() {
+        assert_eq!(
+            strip_flag_value("-i https://pypi.example.com/simple", &["-i", "--index-url"]),
+            Some("https://pypi.example.com/simple")
+        );
+        assert_eq!(
+            strip_flag_value(
+                "--index-url=https://pypi.example.com/simple",
+                &["-i", "--index-url"]
+            ),
+            Some("https://pypi.example.com/simple")
+        );
+        assert_eq!(
+            strip_flag_value("--extra-index-url https://internal/simple", &["-i"]),
+            None
+        );
+        assert_eq!(strip_flag_value("requests>=2.0", &["-i", "--index-url"]), None);
+    }
+
+    #[test]
+    fn ensure_source_dedupes_by_url() {
+        let mut pipfile = Pipfile::default_new();
+
+        let first = ensure_source(&mut pipfile, "https://pypi.example.com/simple");
+        let second = ensure_source(&mut pipfile, "https://pypi.example.com/simple");
+
+        assert_eq!(first, second);
+        assert_eq!(pipfile.source.len(), 1);
+    }
+
+    #[test]
+    fn ensure_source_avoids_name_collisions() {
+        let mut pipfile = Pipfile::default_new();
+
+        let first = ensure_source(&mut pipfile, "https://pypi.example.com/simple");
+        let second = ensure_source(&mut pipfile, "https://pypi.example.com/other");
+
+        assert_ne!(first, second);
+        assert_eq!(pipfile.source.len(), 2);
+    }
+
+    #[test]
+    fn parse_package_spec_with_index_produces_detailed_entry() {
+        let args = InstallArgs {
+            packages: vec![],
+            no_dev: false,
+            system: false,
+            deploy: false,
+            requirements: None,
+            dev_packages: false,
+            pre: false,
+            editable: false,
+            skip_lock: false,
+            index: None,
+            extra_index_url: vec![],
+            keep_outdated: false,
+        };
+
+        let (name, package) = parse_package_spec("requests>=2.32.0", &args, Some("private"));
+
+        assert_eq!(name, "requests");
+        match package {
+            PipfilePackage::Detailed(detail) => {
+                assert_eq!(detail.version.as_deref(), Some(">=2.32.0"));
+                assert_eq!(detail.index.as_deref(), Some("private"));
+            }
+            PipfilePackage::Simple(_) => panic!("expected a detailed package with an index"),
+        }
+    }
+
+    #[test]
+    fn join_line_continuations_joins_backslash_continued_lines() {
+        let content = "requests==2.32.0 \\\n    --hash=sha256:aaa \\\n    --hash=sha256:bbb\nflask\n";
+        let lines = join_line_continuations(content);
+
+        assert_eq!(
+            lines,
+            vec![
+                "requests==2.32.0 --hash=sha256:aaa --hash=sha256:bbb".to_owned(),
+                "flask".to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_name_extras_version_parses_bracketed_extras() {
+        let (name, extras, version) = split_name_extras_version("requests[security,socks]>=2.0");
+        assert_eq!(name, "requests");
+        assert_eq!(extras, vec!["security".to_owned(), "socks".to_owned()]);
+        assert_eq!(version, ">=2.0");
+    }
+
+    #[test]
+    fn split_name_extras_version_without_extras_falls_back() {
+        let (name, extras, version) = split_name_extras_version("requests>=2.0");
+        assert_eq!(name, "requests");
+        assert!(extras.is_empty());
+        assert_eq!(version, ">=2.0");
+    }
+
+    #[test]
+    fn parse_requirement_line_captures_extras_markers_and_hashes() {
+        let (name, package) = parse_requirement_line(
+            "requests[security]==2.32.0; python_version < \"3.9\" --hash=sha256:aaa --hash=sha256:bbb",
+            None,
+        );
+
+        assert_eq!(name, "requests");
+        match package {
+            PipfilePackage::Detailed(detail) => {
+                assert_eq!(detail.version.as_deref(), Some("==2.32.0"));
+                assert_eq!(detail.extras, vec!["security".to_owned()]);
+                assert_eq!(detail.markers.as_deref(), Some("python_version < \"3.9\""));
+                assert_eq!(
+                    detail.hashes,
+                    vec!["sha256:aaa".to_owned(), "sha256:bbb".to_owned()]
+                );
+            }
+            PipfilePackage::Simple(_) => panic!("expected a detailed package"),
+        }
+    }
+
+    #[test]
+    fn parse_requirement_line_without_extras_markers_hashes_stays_simple() {
+        let (name, package) = parse_requirement_line("flask>=3.0", None);
+        assert_eq!(name, "flask");
+        assert!(matches!(package, PipfilePackage::Simple(version) if version == ">=3.0"));
+    }
+
+    #[test]
+    fn parse_editable_spec_sets_path_and_editable() {
+        let (name, package) = parse_editable_spec("./local-pkg");
+        assert_eq!(name, "./local-pkg");
+        match package {
+            PipfilePackage::Detailed(detail) => {
+                assert_eq!(detail.path.as_deref(), Some("./local-pkg"));
+                assert!(detail.editable);
+            }
+            PipfilePackage::Simple(_) => panic!("expected a detailed editable package"),
+        }
+    }
+
+    #[test]
+    fn import_requirements_follows_includes_and_routes_editable() {
+        let dir = tempfile::TempDir::new().unwrap();
+
+        fs_err::write(dir.path().join("base.txt"), "flask>=3.0\n").unwrap();
+        fs_err::write(
+            dir.path().join("requirements.txt"),
+            "-r base.txt\n-e ./local-pkg\nrequests==2.32.0\n",
+        )
+        .unwrap();
+
+        let mut pipfile = Pipfile::default_new();
+        let mut index_name = None;
+        let mut touched = Vec::new();
+        import_requirements(
+            &mut pipfile,
+            &dir.path().join("requirements.txt"),
+            &mut index_name,
+            &mut HashSet::new(),
+            &mut touched,
+            false,
+        )
+        .unwrap();
+
+        assert!(pipfile.packages.contains_key("flask"));
+        assert!(pipfile.packages.contains_key("requests"));
+        match &pipfile.packages["./local-pkg"] {
+            PipfilePackage::Detailed(detail) => assert!(detail.editable),
+            PipfilePackage::Simple(_) => panic!("expected an editable package"),
+        }
+    }
+
+    #[test]
+    fn import_requirements_ignores_include_cycles() {
+        let dir = tempfile::TempDir::new().unwrap();
+
+        fs_err::write(dir.path().join("a.txt"), "-r b.txt\nflask>=3.0\n").unwrap();
+        fs_err::write(dir.path().join("b.txt"), "-r a.txt\nrequests==2.32.0\n").unwrap();
+
+        let mut pipfile = Pipfile::default_new();
+        let mut index_name = None;
+        let mut touched = Vec::new();
+        import_requirements(
+            &mut pipfile,
+            &dir.path().join("a.txt"),
+            &mut index_name,
+            &mut HashSet::new(),
+            &mut touched,
+            false,
+        )
+        .unwrap();
+
+        assert!(pipfile.packages.contains_key("flask"));
+        assert!(pipfile.packages.contains_key("requests"));
+    }
+
+    #[test]
+    fn import_requirements_constraint_file_bounds_existing_dep_without_adding_new_ones() {
+        let dir = tempfile::TempDir::new().unwrap();
+
+        fs_err::write(
+            dir.path().join("constraints.txt"),
+            "flask==3.0.1\nunrelated-pkg==1.0.0\n",
+        )
+        .unwrap();
+        // The constraint is applied to whatever's already in the Pipfile,
+        // so the requirement line must come first.
+        fs_err::write(
+            dir.path().join("requirements.txt"),
+            "flask>=3.0\n-c constraints.txt\n",
+        )
+        .unwrap();
+
+        let mut pipfile = Pipfile::default_new();
+        let mut index_name = None;
+        let mut touched = Vec::new();
+        import_requirements(
+            &mut pipfile,
+            &dir.path().join("requirements.txt"),
+            &mut index_name,
+            &mut HashSet::new(),
+            &mut touched,
+            false,
+        )
+        .unwrap();
+
+        match &pipfile.packages["flask"] {
+            PipfilePackage::Simple(version) => assert_eq!(version, "==3.0.1"),
+            PipfilePackage::Detailed(detail) => {
+                assert_eq!(detail.version.as_deref(), Some("==3.0.1"))
+            }
+        }
+        assert!(
+            !pipfile.packages.contains_key("unrelated-pkg"),
+            "a constraint on a package nothing else requires must not become a new dependency"
+        );
     }
 }
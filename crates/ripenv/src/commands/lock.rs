@@ -40,13 +40,20 @@ pub async fn execute(
             .debug("--dev-only has no effect on lock (all deps are always resolved)");
     }
 
+    // Resolution order: PIPENV_PYTHON > nearest .python-version(s) file > Pipfile `[requires]`.
+    let python_request = crate::pipfile::resolve_python_request(
+        &ctx.project_dir,
+        std::env::var("PIPENV_PYTHON").ok().as_deref(),
+        ctx.pipfile.requires.as_ref(),
+    );
+
     let result = uv::commands::project::lock::lock(
         &ctx.project_dir,
         uv::settings::LockCheck::Disabled,
         None, // frozen
         DryRun::default(),
         refresh,
-        None, // python
+        python_request,
         ctx.install_mirrors(),
         settings,
         ctx.client_builder(),
@@ -62,7 +69,7 @@ pub async fn execute(
     .await?;
 
     if matches!(result, ExitStatus::Success) {
-        ctx.generate_pipfile_lock()?;
+        ctx.generate_pipfile_lock(Some(cache.root()), args.require_hashes)?;
         ctx.printer.info("Locking successful.");
     }
 
@@ -11,12 +11,19 @@ use anyhow::Result;
 use crate::cli;
 use crate::printer::Printer;
 
+pub mod build;
+pub mod completions;
+pub mod diff;
+pub mod events;
+pub mod global;
 pub mod install;
 pub mod lock;
+pub mod project_ops;
 pub mod run;
 pub mod sync;
 pub mod uninstall;
 pub mod update;
+pub mod update_plan;
 pub mod uv_runner;
 
 /// Exit status for ripenv commands.
@@ -61,6 +68,11 @@ pub async fn dispatch(
         cli::Commands::Sync(ref args) => sync::execute(args, printer, verbosity, quiet),
         cli::Commands::Update(ref args) => update::execute(args, printer, verbosity, quiet),
         cli::Commands::Run(ref args) => run::execute(args, printer, verbosity, quiet),
+        cli::Commands::Build(ref args) => build::execute(args, printer, verbosity, quiet),
+        cli::Commands::Global(ref args) => global::execute(args, printer, verbosity, quiet),
+        cli::Commands::Completions(ref args) => {
+            completions::execute(args, printer, verbosity, quiet)
+        }
         cli::Commands::Check(_) => {
             printer.warn("ripenv check is deprecated. Use `ripenv audit` instead.");
             Ok(ExitStatus::Failure)
@@ -0,0 +1,203 @@
+//! A consolidated facade over the lock/sync calls project commands share.
+//!
+//! `install`, `sync`, and `update` each called
+//! `uv::commands::project::{lock::lock, sync::sync}` directly, threading on
+//! the order of twenty positional arguments apiece, most of which are the
+//! same constants at every call site. [`ProjectOps`] wraps a [`UvContext`]
+//! and exposes the handful of things that actually vary — an upgrade
+//! selection, which groups to install, a python override — as small
+//! options structs, so adding a new project command no longer means
+//! re-deriving the full argument list. `generate_pipfile_lock` is folded
+//! into [`ProjectOps::lock`] as a post-lock step, so no caller can forget
+//! to keep `Pipfile.lock` in sync with `uv.lock` after a real (non-check)
+//! lock succeeds.
+//!
+//! `lock.rs` is left calling uv directly: it needs a couple of knobs
+//! (`--clear`'s cache refresh, `--require-hashes`) that are unique to that
+//! command, and folding them in here would widen this facade's surface for
+//! a single caller.
+
+use uv_cache::Refresh;
+use uv_cli::SyncFormat;
+use uv_configuration::{
+    DependencyGroups, DryRun, EditableMode, ExtrasSpecification, InstallOptions, Upgrade,
+};
+use uv_normalize::PackageName;
+use uv_python::{PythonPreference, PythonRequest};
+use uv_resolver::PrereleaseMode;
+
+use anyhow::Result;
+
+use crate::commands::ExitStatus;
+use crate::commands::uv_runner::UvContext;
+
+/// Options for [`ProjectOps::lock`].
+pub struct LockOptions {
+    /// Whether to actually write a lock, or just check one is up to date.
+    pub check: uv::settings::LockCheck,
+    /// Which packages (if any) to force re-resolution for.
+    pub upgrade: Upgrade,
+    /// Force a fresh cache lookup for these packages even if `upgrade`
+    /// doesn't otherwise select them — used by `update` to advance VCS and
+    /// editable pins whose cache entry would otherwise look up to date.
+    pub refresh: Vec<PackageName>,
+    /// Allow prerelease versions to satisfy the resolution.
+    pub allow_prereleases: bool,
+    /// Resolve without writing `uv.lock` or `Pipfile.lock`.
+    pub dry_run: bool,
+}
+
+impl Default for LockOptions {
+    fn default() -> Self {
+        Self {
+            check: uv::settings::LockCheck::Disabled,
+            upgrade: Upgrade::None,
+            refresh: Vec::new(),
+            allow_prereleases: false,
+            dry_run: false,
+        }
+    }
+}
+
+/// Options for [`ProjectOps::sync`].
+#[derive(Default)]
+pub struct SyncOptions {
+    /// Which dependency groups to install.
+    pub groups: DependencyGroups,
+    /// Overrides [`UvContext::python_preference`] for this sync, e.g.
+    /// `--system`.
+    pub python_preference: Option<PythonPreference>,
+    /// Pins the interpreter for this sync, e.g. a `.python-version` lookup.
+    pub python_request: Option<PythonRequest>,
+}
+
+/// Options for [`ProjectOps::lock_then_sync`].
+#[derive(Default)]
+pub struct LockThenSyncOptions {
+    pub lock: LockOptions,
+    pub sync: SyncOptions,
+    /// Stop after locking, as with `--lock-only`.
+    pub lock_only: bool,
+}
+
+impl UvContext {
+    /// Entry point for [`ProjectOps`]'s consolidated lock/sync methods.
+    pub fn project_ops(&self) -> ProjectOps<'_> {
+        ProjectOps { ctx: self }
+    }
+}
+
+/// Consolidated lock/sync operations over a [`UvContext`].
+///
+/// See the module docs for why this exists.
+pub struct ProjectOps<'a> {
+    ctx: &'a UvContext,
+}
+
+impl ProjectOps<'_> {
+    /// Re-lock the project, then regenerate `Pipfile.lock` from the result
+    /// unless `options.check` or `options.dry_run` means nothing was
+    /// actually written.
+    pub async fn lock(&self, options: LockOptions) -> Result<ExitStatus> {
+        let ctx = self.ctx;
+
+        let mut settings = ctx.resolver_settings();
+        settings.upgrade = options.upgrade;
+        if options.allow_prereleases {
+            settings.prerelease = PrereleaseMode::Allow;
+        }
+
+        let dry_run = if options.dry_run {
+            DryRun::Enabled
+        } else {
+            DryRun::default()
+        };
+
+        let cache = ctx.cache()?;
+
+        let result = uv::commands::project::lock::lock(
+            &ctx.project_dir,
+            options.check,
+            None, // frozen
+            dry_run,
+            Refresh::from_args(None, options.refresh),
+            None, // python
+            ctx.install_mirrors(),
+            settings,
+            ctx.client_builder(),
+            None, // script
+            ctx.python_preference(),
+            ctx.python_downloads(),
+            ctx.concurrency(),
+            false, // no_config
+            &cache,
+            ctx.uv_printer(),
+            ctx.preview(),
+        )
+        .await?;
+
+        if matches!(result, ExitStatus::Success)
+            && matches!(options.check, uv::settings::LockCheck::Disabled)
+            && !options.dry_run
+        {
+            ctx.generate_pipfile_lock(None, false)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Sync the virtualenv with the current lockfile.
+    pub async fn sync(&self, options: SyncOptions) -> Result<ExitStatus> {
+        let ctx = self.ctx;
+
+        let python_preference = options
+            .python_preference
+            .unwrap_or_else(|| ctx.python_preference());
+
+        let cache = ctx.cache()?;
+
+        Box::pin(uv::commands::project::sync::sync(
+            &ctx.project_dir,
+            uv::settings::LockCheck::Disabled,
+            None, // frozen
+            DryRun::default(),
+            None,   // active
+            false,  // all_packages
+            vec![], // package
+            ExtrasSpecification::default(),
+            options.groups,
+            Some(EditableMode::default()),
+            InstallOptions::default(),
+            uv::commands::pip::operations::Modifications::Exact,
+            options.python_request,
+            None, // python_platform
+            ctx.install_mirrors(),
+            python_preference,
+            ctx.python_downloads(),
+            ctx.resolver_installer_settings(),
+            ctx.client_builder(),
+            None,  // script
+            false, // installer_metadata
+            ctx.concurrency(),
+            false, // no_config
+            &cache,
+            ctx.uv_printer(),
+            ctx.preview(),
+            SyncFormat::default(),
+        ))
+        .await
+    }
+
+    /// Lock, then sync, unless `options.lock_only` or the lock itself was a
+    /// dry run — the combination `update.rs` and `install.rs` both need.
+    pub async fn lock_then_sync(&self, options: LockThenSyncOptions) -> Result<ExitStatus> {
+        let skip_sync = options.lock_only || options.lock.dry_run;
+
+        let result = self.lock(options.lock).await?;
+        if !matches!(result, ExitStatus::Success) || skip_sync {
+            return Ok(result);
+        }
+
+        self.sync(options.sync).await
+    }
+}
@@ -1,13 +1,16 @@
 //! `ripenv run` — run a command in the virtualenv, or a Pipfile script.
 
 use std::ffi::OsString;
+use std::path::Path;
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use uv_configuration::{DependencyGroups, EditableMode, EnvFile, ExtrasSpecification};
+use uv_python::PythonRequest;
 
 use crate::cli::RunArgs;
 use crate::commands::ExitStatus;
 use crate::commands::uv_runner::UvContext;
+use crate::pipfile::model::PipfileRequires;
 use crate::printer::Printer;
 
 /// Execute `ripenv run`.
@@ -19,22 +22,31 @@ pub async fn execute(
 ) -> Result<ExitStatus> {
     let ctx = UvContext::discover(printer, verbosity, quiet)?;
 
+    let (version_selector, command_str, command_args) =
+        parse_run_command(&args.command, &args.args)?;
+
     // Check if the command is a Pipfile script
-    let (command, extra_args) = if let Some(script) = ctx.pipfile.scripts.get(&args.command) {
+    let (command, extra_args) = if let Some(script) = ctx.pipfile.scripts.get(&command_str) {
+        let command_line = script.command();
         ctx.printer.debug(&format!(
-            "Expanding script '{}' -> '{script}'",
-            args.command
+            "Expanding script '{command_str}' -> '{command_line}'"
         ));
 
+        // Export the script's declared environment before running it; the
+        // child process inherits the parent's environment.
+        for (key, value) in script.env() {
+            std::env::set_var(key, value);
+        }
+
         // Split the script into command + args
-        let mut parts = script.split_whitespace();
+        let mut parts = command_line.split_whitespace();
         let cmd = parts.next().context("script is empty")?.to_owned();
         let mut script_args: Vec<String> = parts.map(String::from).collect();
         // Append any extra args passed on the command line
-        script_args.extend(args.args.clone());
+        script_args.extend(command_args);
         (cmd, script_args)
     } else {
-        (args.command.clone(), args.args.clone())
+        (command_str, command_args)
     };
 
     // Build a RunCommand::External for uv
@@ -44,6 +56,13 @@ pub async fn execute(
 
     let cache = ctx.cache()?;
 
+    let python_request = resolve_run_python_request(
+        version_selector.as_deref(),
+        &ctx.project_dir,
+        std::env::var("PIPENV_PYTHON").ok().as_deref(),
+        ctx.pipfile.requires.as_ref(),
+    )?;
+
     let result = Box::pin(uv::commands::project::run::run(
         &ctx.project_dir,
         None, // script (PEP 723)
@@ -63,7 +82,7 @@ pub async fn execute(
         DependencyGroups::default(),
         Some(EditableMode::default()),
         uv::commands::pip::operations::Modifications::Sufficient,
-        None, // python
+        python_request,
         None, // python_platform
         ctx.install_mirrors(),
         ctx.resolver_installer_settings(),
@@ -82,3 +101,133 @@ pub async fn execute(
 
     Ok(result)
 }
+
+/// Split a `ripenv run [+VERSION] <command> [args...]` invocation into an
+/// optional Python version selector and the command to actually run.
+///
+/// A leading `+3.11`/`+pypy@3.10`-style token pins the interpreter for
+/// this one invocation, mirroring uv's Python shim selectors, and is
+/// stripped off before the rest of the args are treated as the command
+/// to run. Errors if a selector is given with nothing left to run.
+fn parse_run_command(
+    command: &str,
+    args: &[String],
+) -> Result<(Option<String>, String, Vec<String>)> {
+    match command.strip_prefix('+') {
+        Some(selector) => {
+            let (cmd, rest) = args
+                .split_first()
+                .context("`ripenv run +VERSION` requires a command to run")?;
+            Ok((Some(selector.to_owned()), cmd.clone(), rest.to_vec()))
+        }
+        None => Ok((None, command.to_owned(), args.to_vec())),
+    }
+}
+
+/// Resolve the Python interpreter request for `ripenv run`, honoring
+/// precedence: an explicit `+VERSION` selector (see [`parse_run_command`])
+/// first, then `PIPENV_PYTHON`, then the nearest `.python-version` file,
+/// then the Pipfile's `[requires]` table (the latter three handled by
+/// [`crate::pipfile::resolve_python_request`]).
+fn resolve_run_python_request(
+    version_selector: Option<&str>,
+    project_dir: &Path,
+    pipenv_python: Option<&str>,
+    requires: Option<&PipfileRequires>,
+) -> Result<Option<PythonRequest>> {
+    let Some(selector) = version_selector else {
+        return Ok(crate::pipfile::resolve_python_request(
+            project_dir,
+            pipenv_python,
+            requires,
+        ));
+    };
+
+    let request = PythonRequest::parse(selector);
+    if matches!(request, PythonRequest::Default | PythonRequest::Any) {
+        bail!("'+{selector}' is not a recognized Python version selector");
+    }
+    Ok(Some(request))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_run_command_without_selector_passes_through() {
+        let (selector, command, args) =
+            parse_run_command("pytest", &["-vvs".to_owned()]).unwrap();
+
+        assert_eq!(selector, None);
+        assert_eq!(command, "pytest");
+        assert_eq!(args, vec!["-vvs".to_owned()]);
+    }
+
+    #[test]
+    fn parse_run_command_strips_version_selector() {
+        let (selector, command, args) = parse_run_command(
+            "+3.11",
+            &["pytest".to_owned(), "-vvs".to_owned()],
+        )
+        .unwrap();
+
+        assert_eq!(selector.as_deref(), Some("3.11"));
+        assert_eq!(command, "pytest");
+        assert_eq!(args, vec!["-vvs".to_owned()]);
+    }
+
+    #[test]
+    fn parse_run_command_with_selector_and_no_command_errors() {
+        let result = parse_run_command("+3.11", &[]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resolve_run_python_request_selector_takes_precedence() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs_err::write(dir.path().join(".python-version"), "3.9\n").unwrap();
+
+        let requires = PipfileRequires {
+            python_version: Some("3.8".to_owned()),
+            python_full_version: None,
+        };
+
+        // The `PIPENV_PYTHON`, `.python-version`, and `[requires]`
+        // signals all disagree with the selector; the selector should
+        // still win.
+        let request = resolve_run_python_request(
+            Some("3.11"),
+            dir.path(),
+            Some("3.10"),
+            Some(&requires),
+        )
+        .unwrap();
+
+        assert_eq!(request, Some(PythonRequest::parse("3.11")));
+    }
+
+    #[test]
+    fn resolve_run_python_request_falls_back_without_selector() {
+        let dir = tempfile::TempDir::new().unwrap();
+
+        let requires = PipfileRequires {
+            python_version: Some("3.8".to_owned()),
+            python_full_version: None,
+        };
+
+        let request = resolve_run_python_request(None, dir.path(), None, Some(&requires)).unwrap();
+        assert_eq!(request, Some(PythonRequest::parse("3.8")));
+    }
+
+    #[test]
+    fn resolve_run_python_request_rejects_bare_plus_selector() {
+        // `ripenv run +` (no version after the `+`) parses to an empty
+        // selector, which `PythonRequest::parse` treats as `Default` —
+        // not a real pin, so it should be rejected rather than silently
+        // falling back to uv's default interpreter selection.
+        let dir = tempfile::TempDir::new().unwrap();
+        let result = resolve_run_python_request(Some(""), dir.path(), None, None);
+        assert!(result.is_err());
+    }
+}
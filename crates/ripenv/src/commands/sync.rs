@@ -1,13 +1,13 @@
 //! `ripenv sync` — sync the virtualenv with the lockfile.
 
 use anyhow::Result;
-use uv_cli::SyncFormat;
-use uv_configuration::{
-    DependencyGroups, DryRun, EditableMode, ExtrasSpecification, InstallOptions,
-};
+use uv_configuration::DependencyGroups;
 
 use crate::cli::SyncArgs;
 use crate::commands::ExitStatus;
+use crate::commands::diff::{PackageSnapshot, diff_snapshots, print_diff_summary};
+use crate::commands::events::Event;
+use crate::commands::project_ops::SyncOptions;
 use crate::commands::uv_runner::UvContext;
 use crate::printer::Printer;
 
@@ -32,45 +32,44 @@ pub async fn execute(
     );
 
     let python_preference = if args.system {
-        uv_python::PythonPreference::System
+        Some(uv_python::PythonPreference::System)
     } else {
-        ctx.python_preference()
+        None
     };
 
-    let cache = ctx.cache()?;
-
-    let result = Box::pin(uv::commands::project::sync::sync(
+    // Resolution order: PIPENV_PYTHON > nearest .python-version(s) file > Pipfile `[requires]`.
+    let python_request = crate::pipfile::resolve_python_request(
         &ctx.project_dir,
-        uv::settings::LockCheck::Disabled,
-        None, // frozen
-        DryRun::default(),
-        None,   // active
-        false,  // all_packages
-        vec![], // package
-        ExtrasSpecification::default(),
-        groups,
-        Some(EditableMode::default()),
-        InstallOptions::default(),
-        uv::commands::pip::operations::Modifications::Exact,
-        None, // python
-        None, // python_platform
-        ctx.install_mirrors(),
-        python_preference,
-        ctx.python_downloads(),
-        ctx.resolver_installer_settings(),
-        ctx.client_builder(),
-        None,  // script
-        false, // installer_metadata
-        ctx.concurrency(),
-        false, // no_config
-        &cache,
-        ctx.uv_printer(),
-        ctx.preview(),
-        SyncFormat::default(),
-    ))
-    .await?;
+        std::env::var("PIPENV_PYTHON").ok().as_deref(),
+        ctx.pipfile.requires.as_ref(),
+    );
+
+    // Snapshot the virtualenv before syncing so we can print a `+`/`-`/`~`
+    // changelog afterward, mirroring uv's install-output style.
+    let before = ctx
+        .environment()
+        .ok()
+        .and_then(|env| PackageSnapshot::capture(&env).ok());
+
+    let result = ctx
+        .project_ops()
+        .sync(SyncOptions {
+            groups,
+            python_preference,
+            python_request,
+        })
+        .await?;
 
     if matches!(result, ExitStatus::Success) {
+        if let (Some(before), Ok(env)) = (before, ctx.environment()) {
+            if let Ok(after) = PackageSnapshot::capture(&env) {
+                let changes = diff_snapshots(&before, &after);
+                print_diff_summary(&ctx.printer, &changes);
+                ctx.printer.emit_json(&Event::Sync {
+                    changes: changes.iter().map(Into::into).collect(),
+                });
+            }
+        }
         ctx.printer.info("Sync complete.");
     }
 
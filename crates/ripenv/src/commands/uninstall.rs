@@ -9,6 +9,7 @@ use uv_configuration::{
 
 use crate::cli::UninstallArgs;
 use crate::commands::ExitStatus;
+use crate::commands::diff::{PackageSnapshot, diff_snapshots, print_diff_summary};
 use crate::commands::uv_runner::UvContext;
 use crate::printer::Printer;
 
@@ -88,6 +89,11 @@ pub async fn execute(
     }
 
     // Sync to remove unneeded packages from venv
+    let before = ctx
+        .environment()
+        .ok()
+        .and_then(|env| PackageSnapshot::capture(&env).ok());
+
     let result = Box::pin(uv::commands::project::sync::sync(
         &ctx.project_dir,
         uv::settings::LockCheck::Disabled,
@@ -120,6 +126,11 @@ pub async fn execute(
     .await?;
 
     if matches!(result, ExitStatus::Success) {
+        if let (Some(before), Ok(env)) = (before, ctx.environment()) {
+            if let Ok(after) = PackageSnapshot::capture(&env) {
+                print_diff_summary(&ctx.printer, &diff_snapshots(&before, &after));
+            }
+        }
         ctx.printer.info("Uninstall complete.");
     }
 
@@ -1,19 +1,29 @@
 //! `ripenv update` — update packages (re-lock then sync).
+//!
+//! Unlike `lock`, `update` forces re-resolution so pins can actually move.
+//! It also has to go further than a normal upgrade for git/editable
+//! dependencies: uv's cache considers a VCS checkout stable once it has
+//! resolved a given branch/tag once, so without an explicit refresh the
+//! locked commit hash never advances even though the Pipfile's `git`/`ref`
+//! spec is unchanged. We force-refresh exactly the packages backed by a
+//! `git` + `ref` or `editable` + `path` spec so `update` mirrors pipenv's
+//! behavior of always advancing VCS pins to the latest matching commit.
 
 use std::str::FromStr;
 
 use anyhow::Result;
 use rustc_hash::FxHashMap;
-use uv_cache::Refresh;
-use uv_cli::SyncFormat;
-use uv_configuration::{
-    DependencyGroups, DryRun, EditableMode, ExtrasSpecification, InstallOptions, Upgrade,
-};
+use uv_configuration::Upgrade;
 use uv_normalize::PackageName;
 
 use crate::cli::UpdateArgs;
 use crate::commands::ExitStatus;
+use crate::commands::events::Event;
+use crate::commands::project_ops::{LockOptions, LockThenSyncOptions, SyncOptions};
+use crate::commands::update_plan::{LockChange, diff_lockfiles, print_update_plan, scratch_lock_diff};
 use crate::commands::uv_runner::UvContext;
+use crate::pipfile::lockfile::PipfileLock;
+use crate::pipfile::model::{Pipfile, PipfilePackage, PipfilePackageDetail};
 use crate::printer::Printer;
 
 /// Execute `ripenv update`.
@@ -25,47 +35,84 @@ pub async fn execute(
 ) -> Result<ExitStatus> {
     let ctx = UvContext::discover(printer, verbosity, quiet)?;
 
-    // Build lock settings with upgrade
-    let mut settings = ctx.resolver_settings();
+    if args.outdated {
+        return report_outdated(&ctx).await;
+    }
 
-    if args.packages.is_empty() {
-        settings.upgrade = Upgrade::All;
+    let upgrade = if args.packages.is_empty() {
+        Upgrade::All
     } else {
         let mut packages = FxHashMap::default();
         for p in &args.packages {
             let name = PackageName::from_str(p)?;
             packages.insert(name, vec![]);
         }
-        settings.upgrade = Upgrade::Packages(packages);
+        Upgrade::Packages(packages)
+    };
+
+    // Force-refresh VCS/editable packages so their locked commit hash
+    // advances even when the Pipfile spec itself hasn't changed.
+    let refresh = vcs_and_editable_package_names(&ctx.pipfile);
+
+    if args.dry_run {
+        let (result, changes) = scratch_lock_diff(
+            &ctx,
+            LockOptions {
+                upgrade,
+                refresh,
+                ..LockOptions::default()
+            },
+        )
+        .await?;
+
+        if matches!(result, ExitStatus::Success) {
+            print_update_plan(&ctx.printer, &changes);
+            ctx.printer.emit_json(&Event::Update {
+                changes: changes.iter().map(Into::into).collect(),
+                synced: false,
+            });
+        }
+
+        return Ok(result);
     }
 
-    let dry_run = if args.dry_run {
-        DryRun::Enabled
-    } else {
-        DryRun::default()
-    };
+    let before = PipfileLock::read_from(&ctx.project_dir)?;
 
-    let cache = ctx.cache()?;
-
-    // Re-lock
-    let result = uv::commands::project::lock::lock(
-        &ctx.project_dir,
-        uv::settings::LockCheck::Disabled,
-        None, // frozen
-        dry_run,
-        Refresh::from_args(None, vec![]),
-        None, // python
-        ctx.install_mirrors(),
-        settings,
-        ctx.client_builder(),
-        None, // script
-        ctx.python_preference(),
-        ctx.python_downloads(),
-        ctx.concurrency(),
-        false, // no_config
-        &cache,
-        ctx.uv_printer(),
-        ctx.preview(),
+    let result = ctx
+        .project_ops()
+        .lock_then_sync(LockThenSyncOptions {
+            lock: LockOptions {
+                upgrade,
+                refresh,
+                ..LockOptions::default()
+            },
+            sync: SyncOptions::default(),
+            lock_only: args.lock_only,
+        })
+        .await?;
+
+    if matches!(result, ExitStatus::Success) {
+        let after = PipfileLock::read_from(&ctx.project_dir)?;
+        let changes = diff_lockfiles(before.as_ref(), after.as_ref());
+        ctx.printer.emit_json(&Event::Update {
+            changes: changes.iter().map(Into::into).collect(),
+            synced: !args.lock_only,
+        });
+        ctx.printer.info("Update complete.");
+    }
+
+    Ok(result)
+}
+
+/// `ripenv update --outdated` — report packages whose locked version
+/// trails what's newly resolvable, without writing anything.
+async fn report_outdated(ctx: &UvContext) -> Result<ExitStatus> {
+    let (result, changes) = scratch_lock_diff(
+        ctx,
+        LockOptions {
+            upgrade: Upgrade::All,
+            ..LockOptions::default()
+        },
     )
     .await?;
 
@@ -73,46 +120,99 @@ pub async fn execute(
         return Ok(result);
     }
 
-    ctx.generate_pipfile_lock()?;
-
-    // Sync (unless --lock-only or --dry-run)
-    if !args.lock_only && !args.dry_run {
-        let result = Box::pin(uv::commands::project::sync::sync(
-            &ctx.project_dir,
-            uv::settings::LockCheck::Disabled,
-            None, // frozen
-            DryRun::default(),
-            None,   // active
-            false,  // all_packages
-            vec![], // package
-            ExtrasSpecification::default(),
-            DependencyGroups::default(),
-            Some(EditableMode::default()),
-            InstallOptions::default(),
-            uv::commands::pip::operations::Modifications::Exact,
-            None, // python
-            None, // python_platform
-            ctx.install_mirrors(),
-            ctx.python_preference(),
-            ctx.python_downloads(),
-            ctx.resolver_installer_settings(),
-            ctx.client_builder(),
-            None,  // script
-            false, // installer_metadata
-            ctx.concurrency(),
-            false, // no_config
-            &cache,
-            ctx.uv_printer(),
-            ctx.preview(),
-            SyncFormat::default(),
-        ))
-        .await?;
+    let outdated: Vec<_> = changes
+        .into_iter()
+        .filter(|(_, change)| matches!(change, LockChange::Upgraded { .. }))
+        .collect();
 
-        if !matches!(result, ExitStatus::Success) {
-            return Ok(result);
-        }
+    if outdated.is_empty() {
+        ctx.printer.info("All packages are up to date.");
+    } else {
+        print_update_plan(&ctx.printer, &outdated);
     }
+    ctx.printer.emit_json(&Event::Outdated {
+        changes: outdated.iter().map(Into::into).collect(),
+    });
 
-    ctx.printer.info("Update complete.");
     Ok(ExitStatus::Success)
 }
+
+/// Collect the names of packages backed by a tracked VCS ref or an editable
+/// local path, across both `[packages]` and `[dev-packages]`.
+///
+/// These are the entries `update` must force through uv's cache refresh:
+/// a `git` + `ref` spec tracks a branch/tag rather than a fixed commit, and
+/// an `editable` + `path` spec is re-read from disk on every resolution, so
+/// both need a fresh look even when the Pipfile text hasn't changed.
+fn vcs_and_editable_package_names(pipfile: &Pipfile) -> Vec<PackageName> {
+    pipfile
+        .packages
+        .iter()
+        .chain(pipfile.dev_packages.iter())
+        .filter_map(|(name, package)| {
+            if is_tracked_vcs_or_editable(package) {
+                PackageName::from_str(name).ok()
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Whether a package spec is a VCS dependency tracking a branch/tag, or an
+/// editable local path — the two cases where the locked pin can go stale
+/// without the Pipfile itself changing.
+fn is_tracked_vcs_or_editable(package: &PipfilePackage) -> bool {
+    match package {
+        PipfilePackage::Simple(_) => false,
+        PipfilePackage::Detailed(detail) => is_tracked_git(detail) || is_editable_path(detail),
+    }
+}
+
+fn is_tracked_git(detail: &PipfilePackageDetail) -> bool {
+    detail.git.is_some() && detail.git_ref.is_some()
+}
+
+fn is_editable_path(detail: &PipfilePackageDetail) -> bool {
+    detail.editable && detail.path.is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vcs_and_editable_names_includes_git_and_editable_only() {
+        let mut pipfile = Pipfile::default_new();
+        pipfile.packages.insert(
+            "tracked-git".to_owned(),
+            PipfilePackage::Detailed(PipfilePackageDetail {
+                git: Some("https://github.com/example/tracked-git".to_owned()),
+                git_ref: Some("main".to_owned()),
+                ..PipfilePackageDetail::default()
+            }),
+        );
+        pipfile.packages.insert(
+            "pinned-requests".to_owned(),
+            PipfilePackage::Simple(">=2.0".to_owned()),
+        );
+        pipfile.dev_packages.insert(
+            "editable-local".to_owned(),
+            PipfilePackage::Detailed(PipfilePackageDetail {
+                path: Some("./local-pkg".to_owned()),
+                editable: true,
+                ..PipfilePackageDetail::default()
+            }),
+        );
+
+        let names: Vec<String> = vcs_and_editable_package_names(&pipfile)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        assert!(names.contains(&"tracked-git".to_owned()));
+        assert!(names.contains(&"editable-local".to_owned()));
+        assert!(!names.contains(&"pinned-requests".to_owned()));
+        assert_eq!(names.len(), 2);
+    }
+}
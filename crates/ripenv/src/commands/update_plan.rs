@@ -0,0 +1,433 @@
+//! Build an "update plan" by diffing two `Pipfile.lock` resolutions.
+//!
+//! Unlike [`crate::commands::diff`], which compares what's actually
+//! installed in the virtualenv, this compares the *locked* package sets
+//! across the `default`/`develop` groups — used by `ripenv update
+//! --dry-run` (current lock vs. a freshly re-resolved one) and `--outdated`
+//! (current lock vs. an `Upgrade::All` resolution) to report what would
+//! change without touching `Pipfile.lock`, `uv.lock`, or the environment.
+//!
+//! Since [`crate::commands::uv_runner::UvContext`] only exposes lock/sync
+//! as "do it for real" operations, producing a throwaway resolution means
+//! actually re-locking and then restoring the original lockfiles from a
+//! snapshot taken beforehand — there's no dry-run mode that hands back the
+//! resolved [`uv_resolver::Lock`] without writing it to disk first.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use uv_pep440::Version;
+
+use crate::commands::ExitStatus;
+use crate::commands::project_ops::LockOptions;
+use crate::commands::uv_runner::UvContext;
+use crate::pipfile::lockfile::{PipfileLock, PipfileLockPackage};
+use crate::printer::Printer;
+
+/// Which Pipfile dependency group a locked package belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Group {
+    Default,
+    Develop,
+}
+
+impl Group {
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Develop => "develop",
+        }
+    }
+}
+
+/// How a locked package's pin changed between two resolutions.
+#[derive(Debug, PartialEq, Eq)]
+pub enum LockChange {
+    Added { name: String, version: String },
+    Removed { name: String, version: String },
+    Upgraded { name: String, from: String, to: String },
+    Downgraded { name: String, from: String, to: String },
+    /// Same version, but its source (index, git remote, path...) changed.
+    Changed { name: String },
+}
+
+fn change_name(change: &LockChange) -> &str {
+    match change {
+        LockChange::Added { name, .. }
+        | LockChange::Removed { name, .. }
+        | LockChange::Upgraded { name, .. }
+        | LockChange::Downgraded { name, .. }
+        | LockChange::Changed { name, .. } => name,
+    }
+}
+
+/// Re-lock into a scratch resolution, diff it against the current
+/// `Pipfile.lock`, then restore the original `Pipfile.lock`/`uv.lock` so
+/// nothing on disk changes. The environment is never touched, since this
+/// never syncs.
+///
+/// Returns the lock's own exit status alongside the diff; a non-success
+/// status means the scratch lock failed and the diff is empty.
+pub async fn scratch_lock_diff(
+    ctx: &UvContext,
+    options: LockOptions,
+) -> Result<(ExitStatus, Vec<(Group, LockChange)>)> {
+    let snapshot = LockSnapshot::capture(&ctx.project_dir)?;
+    let before = PipfileLock::read_from(&ctx.project_dir)?;
+
+    let lock_result = ctx.project_ops().lock(options).await;
+
+    let after = match &lock_result {
+        Ok(ExitStatus::Success) => PipfileLock::read_from(&ctx.project_dir)?,
+        _ => None,
+    };
+
+    snapshot.restore(&ctx.project_dir)?;
+
+    let result = lock_result?;
+    if !matches!(result, ExitStatus::Success) {
+        return Ok((result, Vec::new()));
+    }
+
+    Ok((result, diff_lockfiles(before.as_ref(), after.as_ref())))
+}
+
+/// Diff two (optional) `Pipfile.lock`s, group by group.
+///
+/// Either side may be `None` — a project with no lock yet resolves to "all
+/// added", for instance.
+pub(crate) fn diff_lockfiles(
+    before: Option<&PipfileLock>,
+    after: Option<&PipfileLock>,
+) -> Vec<(Group, LockChange)> {
+    let empty = BTreeMap::new();
+    let mut changes = diff_group(
+        Group::Default,
+        before.map_or(&empty, |lock| &lock.default),
+        after.map_or(&empty, |lock| &lock.default),
+    );
+    changes.extend(diff_group(
+        Group::Develop,
+        before.map_or(&empty, |lock| &lock.develop),
+        after.map_or(&empty, |lock| &lock.develop),
+    ));
+    changes
+}
+
+fn diff_group(
+    group: Group,
+    before: &BTreeMap<String, PipfileLockPackage>,
+    after: &BTreeMap<String, PipfileLockPackage>,
+) -> Vec<(Group, LockChange)> {
+    let mut changes = Vec::new();
+
+    for (name, before_pkg) in before {
+        match after.get(name) {
+            None => changes.push((
+                group,
+                LockChange::Removed {
+                    name: name.clone(),
+                    version: version_label(before_pkg),
+                },
+            )),
+            Some(after_pkg) => {
+                if let Some(change) = classify_change(name, before_pkg, after_pkg) {
+                    changes.push((group, change));
+                }
+            }
+        }
+    }
+
+    for (name, after_pkg) in after {
+        if !before.contains_key(name) {
+            changes.push((
+                group,
+                LockChange::Added {
+                    name: name.clone(),
+                    version: version_label(after_pkg),
+                },
+            ));
+        }
+    }
+
+    changes.sort_by(|a, b| change_name(&a.1).cmp(change_name(&b.1)));
+    changes
+}
+
+/// Classify the change (if any) between two locked entries for the same
+/// package name. `None` means nothing worth reporting changed.
+fn classify_change(
+    name: &str,
+    before: &PipfileLockPackage,
+    after: &PipfileLockPackage,
+) -> Option<LockChange> {
+    let before_version = version_label(before);
+    let after_version = version_label(after);
+
+    if before_version != after_version {
+        return Some(match compare_versions(&before_version, &after_version) {
+            Some(std::cmp::Ordering::Greater) => LockChange::Downgraded {
+                name: name.to_owned(),
+                from: before_version,
+                to: after_version,
+            },
+            _ => LockChange::Upgraded {
+                name: name.to_owned(),
+                from: before_version,
+                to: after_version,
+            },
+        });
+    }
+
+    // Same version, but did the package move source/index? A package's
+    // pin can look unchanged while the index or git remote it's pulled
+    // from has moved — worth flagging even though the version matches.
+    let moved = before.index != after.index
+        || before.git != after.git
+        || before.file != after.file
+        || before.path != after.path;
+    if moved {
+        return Some(LockChange::Changed {
+            name: name.to_owned(),
+        });
+    }
+
+    None
+}
+
+/// A package's display version: its registry/direct-URL version (with the
+/// `==` pin stripped), or a git ref, or `"local"` for a path dependency.
+fn version_label(pkg: &PipfileLockPackage) -> String {
+    if let Some(version) = &pkg.version {
+        version.strip_prefix("==").unwrap_or(version).to_owned()
+    } else if let Some(git_ref) = &pkg.git_ref {
+        git_ref.clone()
+    } else if pkg.path.is_some() {
+        "local".to_owned()
+    } else {
+        "unknown".to_owned()
+    }
+}
+
+/// Compare two version labels as PEP 440 versions. `None` if either side
+/// isn't a parseable version (e.g. a git ref), in which case the caller
+/// treats the change as an upgrade rather than guessing at direction.
+fn compare_versions(before: &str, after: &str) -> Option<std::cmp::Ordering> {
+    let before = Version::from_str(before).ok()?;
+    let after = Version::from_str(after).ok()?;
+    Some(before.cmp(&after))
+}
+
+/// Print a colorized update plan, grouped by dependency group.
+pub fn print_update_plan(printer: &Printer, changes: &[(Group, LockChange)]) {
+    if changes.is_empty() {
+        printer.info("No changes.");
+        return;
+    }
+
+    for group in [Group::Default, Group::Develop] {
+        let group_changes: Vec<&LockChange> = changes
+            .iter()
+            .filter(|(g, _)| *g == group)
+            .map(|(_, change)| change)
+            .collect();
+        if group_changes.is_empty() {
+            continue;
+        }
+
+        printer.info(&format!("{}:", group.label().bold()));
+        for change in group_changes {
+            printer.info(&format!("  {}", format_lock_change(change)));
+        }
+    }
+}
+
+fn format_lock_change(change: &LockChange) -> String {
+    match change {
+        LockChange::Added { name, version } => format!("{} {name}=={version}", "+".green()),
+        LockChange::Removed { name, version } => format!("{} {name}=={version}", "-".red()),
+        LockChange::Upgraded { name, from, to } => format!("{} {name} {from} -> {to}", "~".yellow()),
+        LockChange::Downgraded { name, from, to } => {
+            format!("{} {name} {from} -> {to}", "~".yellow())
+        }
+        LockChange::Changed { name } => format!("{} {name} (source changed)", "~".yellow()),
+    }
+}
+
+/// Snapshot of `Pipfile.lock`/`uv.lock`'s raw contents, so a scratch lock
+/// can be undone exactly — including the case where one or both files
+/// didn't exist yet.
+struct LockSnapshot {
+    pipfile_lock: Option<String>,
+    uv_lock: Option<String>,
+}
+
+impl LockSnapshot {
+    fn capture(project_dir: &Path) -> Result<Self> {
+        Ok(Self {
+            pipfile_lock: read_if_exists(&project_dir.join("Pipfile.lock"))?,
+            uv_lock: read_if_exists(&project_dir.join("uv.lock"))?,
+        })
+    }
+
+    fn restore(&self, project_dir: &Path) -> Result<()> {
+        restore_file(&project_dir.join("Pipfile.lock"), self.pipfile_lock.as_deref())?;
+        restore_file(&project_dir.join("uv.lock"), self.uv_lock.as_deref())?;
+        Ok(())
+    }
+}
+
+fn read_if_exists(path: &Path) -> Result<Option<String>> {
+    if path.is_file() {
+        Ok(Some(fs_err::read_to_string(path)?))
+    } else {
+        Ok(None)
+    }
+}
+
+fn restore_file(path: &Path, content: Option<&str>) -> Result<()> {
+    match content {
+        Some(content) => fs_err::write(path, content)?,
+        None if path.is_file() => fs_err::remove_file(path)?,
+        None => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(version: &str) -> PipfileLockPackage {
+        PipfileLockPackage {
+            hashes: Vec::new(),
+            index: Some("pypi".to_owned()),
+            markers: None,
+            version: Some(format!("=={version}")),
+            git: None,
+            git_ref: None,
+            file: None,
+            path: None,
+            editable: false,
+        }
+    }
+
+    fn lock(default: &[(&str, &str)], develop: &[(&str, &str)]) -> PipfileLock {
+        PipfileLock {
+            meta: crate::pipfile::lockfile::PipfileLockMeta {
+                hash: crate::pipfile::lockfile::PipfileLockHash {
+                    sha256: String::new(),
+                },
+                pipfile_spec: 6,
+                requires: serde_json::json!({}),
+                sources: Vec::new(),
+            },
+            default: default
+                .iter()
+                .map(|(name, version)| ((*name).to_owned(), package(version)))
+                .collect(),
+            develop: develop
+                .iter()
+                .map(|(name, version)| ((*name).to_owned(), package(version)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn detects_upgrade() {
+        let before = lock(&[("requests", "2.31.0")], &[]);
+        let after = lock(&[("requests", "2.32.3")], &[]);
+
+        let changes = diff_lockfiles(Some(&before), Some(&after));
+        assert_eq!(
+            changes,
+            vec![(
+                Group::Default,
+                LockChange::Upgraded {
+                    name: "requests".to_owned(),
+                    from: "2.31.0".to_owned(),
+                    to: "2.32.3".to_owned(),
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn detects_downgrade() {
+        let before = lock(&[("requests", "2.32.3")], &[]);
+        let after = lock(&[("requests", "2.31.0")], &[]);
+
+        let changes = diff_lockfiles(Some(&before), Some(&after));
+        assert_eq!(
+            changes,
+            vec![(
+                Group::Default,
+                LockChange::Downgraded {
+                    name: "requests".to_owned(),
+                    from: "2.32.3".to_owned(),
+                    to: "2.31.0".to_owned(),
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn detects_added_and_removed_without_crossing_groups() {
+        let before = lock(&[("requests", "2.32.3")], &[("pytest", "8.0.0")]);
+        let after = lock(&[], &[("pytest", "8.0.0"), ("pluggy", "1.5.0")]);
+
+        let changes = diff_lockfiles(Some(&before), Some(&after));
+        assert_eq!(
+            changes,
+            vec![
+                (
+                    Group::Default,
+                    LockChange::Removed {
+                        name: "requests".to_owned(),
+                        version: "2.32.3".to_owned(),
+                    }
+                ),
+                (
+                    Group::Develop,
+                    LockChange::Added {
+                        name: "pluggy".to_owned(),
+                        version: "1.5.0".to_owned(),
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_source_change_with_identical_version() {
+        let mut after_pkg = package("2.32.3");
+        after_pkg.index = Some("private".to_owned());
+        let mut after = lock(&[], &[]);
+        after.default.insert("requests".to_owned(), after_pkg);
+
+        let mut before = lock(&[], &[]);
+        before.default.insert("requests".to_owned(), package("2.32.3"));
+
+        let changes = diff_lockfiles(Some(&before), Some(&after));
+        assert_eq!(
+            changes,
+            vec![(
+                Group::Default,
+                LockChange::Changed {
+                    name: "requests".to_owned(),
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn unchanged_package_produces_no_diff() {
+        let before = lock(&[("requests", "2.32.3")], &[]);
+        let after = lock(&[("requests", "2.32.3")], &[]);
+
+        assert!(diff_lockfiles(Some(&before), Some(&after)).is_empty());
+    }
+}
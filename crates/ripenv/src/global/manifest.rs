@@ -0,0 +1,164 @@
+//! The `~/.config/ripenv/global.toml` manifest: one `[tool.<name>]` table
+//! per globally-installed CLI, listing the package spec(s) resolved into
+//! its isolated venv.
+//!
+//! Reuses [`PipfilePackage`] for spec syntax so a tool's version pin reads
+//! the same way a Pipfile dependency does (`black = ">=24"` or
+//! `black = {version = ">=24", extras = ["jupyter"]}`), and reuses the
+//! Pipfile writer's `toml_edit` serialization ([`package_to_item`]) so the
+//! two formats stay in sync without duplicating the field mapping.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::pipfile::package_to_item;
+use crate::pipfile::model::PipfilePackage;
+
+/// A single globally-installed tool's environment.
+#[derive(Debug, Default, Deserialize)]
+pub struct GlobalTool {
+    /// Packages installed into this tool's venv, keyed by name. The tool
+    /// itself is always present; additional entries cover plugins
+    /// installed alongside it into the same environment (e.g. a ruff
+    /// formatter plugin).
+    #[serde(default)]
+    pub packages: BTreeMap<String, PipfilePackage>,
+}
+
+/// Top-level `global.toml` structure.
+#[derive(Debug, Default, Deserialize)]
+pub struct GlobalManifest {
+    /// Installed tools, keyed by name (e.g. `black`, `ruff`).
+    #[serde(default, rename = "tool")]
+    pub tools: BTreeMap<String, GlobalTool>,
+}
+
+impl GlobalManifest {
+    /// Load the manifest from [`manifest_path`].
+    ///
+    /// Returns an empty manifest if the file doesn't exist yet (the state
+    /// before any `ripenv global install` has ever run).
+    pub fn load() -> Result<Self> {
+        Self::load_from(&manifest_path()?)
+    }
+
+    /// Write the manifest back to [`manifest_path`], creating the parent
+    /// directory if it doesn't exist yet.
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&manifest_path()?)
+    }
+
+    /// The directory holding every tool's venv, alongside the manifest
+    /// itself.
+    pub fn venvs_dir() -> Result<PathBuf> {
+        Ok(config_dir()?.join("venvs"))
+    }
+
+    /// The dedicated venv directory for a tool's environment, alongside
+    /// the manifest itself.
+    pub fn venv_dir(tool_name: &str) -> Result<PathBuf> {
+        Ok(Self::venvs_dir()?.join(tool_name))
+    }
+
+    fn load_from(path: &Path) -> Result<Self> {
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        let content = fs_err::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+
+        let mut doc = toml_edit::DocumentMut::new();
+        let tool_table = doc
+            .entry("tool")
+            .or_insert_with(|| toml_edit::Item::Table(toml_edit::Table::new()))
+            .as_table_mut()
+            .context("global.toml [tool] must be a table")?;
+
+        for (name, tool) in &self.tools {
+            let mut packages = toml_edit::Table::new();
+            packages.set_implicit(false);
+            for (pkg_name, pkg) in &tool.packages {
+                packages.insert(pkg_name, package_to_item(pkg));
+            }
+
+            let mut table = toml_edit::Table::new();
+            table.insert("packages", toml_edit::Item::Table(packages));
+            tool_table.insert(name, toml_edit::Item::Table(table));
+        }
+
+        fs_err::write(path, doc.to_string())
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        Ok(())
+    }
+}
+
+/// Path to the global manifest file. Honors `RIPENV_GLOBAL_CONFIG` for an
+/// explicit override, mirroring `PIPENV_PIPFILE`'s convention.
+pub fn manifest_path() -> Result<PathBuf> {
+    if let Ok(explicit) = env::var("RIPENV_GLOBAL_CONFIG") {
+        return Ok(PathBuf::from(explicit));
+    }
+    Ok(config_dir()?.join("global.toml"))
+}
+
+/// `~/.config/ripenv` (`%APPDATA%\ripenv` on Windows), honoring
+/// `XDG_CONFIG_HOME` when set.
+fn config_dir() -> Result<PathBuf> {
+    if let Ok(xdg) = env::var("XDG_CONFIG_HOME") {
+        if !xdg.is_empty() {
+            return Ok(PathBuf::from(xdg).join("ripenv"));
+        }
+    }
+    if cfg!(windows) {
+        let appdata = env::var("APPDATA").context("APPDATA is not set")?;
+        return Ok(PathBuf::from(appdata).join("ripenv"));
+    }
+    let home = env::var("HOME").context("HOME is not set")?;
+    Ok(PathBuf::from(home).join(".config").join("ripenv"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_from_missing_file_is_empty() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let manifest = GlobalManifest::load_from(&dir.path().join("global.toml")).unwrap();
+        assert!(manifest.tools.is_empty());
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("global.toml");
+
+        let mut manifest = GlobalManifest::default();
+        manifest.tools.insert(
+            "black".to_owned(),
+            GlobalTool {
+                packages: BTreeMap::from([(
+                    "black".to_owned(),
+                    PipfilePackage::Simple(">=24".to_owned()),
+                )]),
+            },
+        );
+        manifest.save_to(&path).unwrap();
+
+        let reloaded = GlobalManifest::load_from(&path).unwrap();
+        assert_eq!(reloaded.tools.len(), 1);
+        assert!(reloaded.tools["black"].packages.contains_key("black"));
+    }
+}
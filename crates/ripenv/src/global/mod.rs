@@ -0,0 +1,12 @@
+//! Global tool environments: standalone Python CLIs (black, ruff, httpie)
+//! installed into isolated per-tool venvs, independent of any project's
+//! Pipfile.
+//!
+//! Sibling to [`crate::pipfile`] and backed by its own manifest file
+//! (`~/.config/ripenv/global.toml`, see [`manifest::GlobalManifest`]) so
+//! the set of globally-installed tools is itself shareable and
+//! version-controllable, separate from any one project's dependencies.
+
+pub mod manifest;
+
+pub use manifest::GlobalManifest;
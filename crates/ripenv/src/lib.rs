@@ -1,8 +1,9 @@
 //! ripenv: a pipenv-compatible CLI powered by uv.
 //!
 //! This crate provides the main entry point and command dispatch for the ripenv
-//! binary. It parses CLI arguments, sets up a tokio runtime, and delegates to
-//! command handlers that bridge Pipfile semantics onto uv's project machinery.
+//! binary. It resolves Pipfile-defined command aliases, parses CLI arguments,
+//! sets up a tokio runtime, and delegates to command handlers that bridge
+//! Pipfile semantics onto uv's project machinery.
 
 #![deny(clippy::print_stdout, clippy::print_stderr)]
 
@@ -13,32 +14,87 @@ use anstream::eprintln;
 use clap::Parser;
 use owo_colors::OwoColorize;
 
+use tracing_subscriber::EnvFilter;
 use uv_configuration::min_stack_size;
 
 use crate::cli::Cli;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
+pub mod aliases;
 pub mod cli;
 pub mod commands;
+pub mod global;
 pub mod pipfile;
 pub mod printer;
+pub mod suggest;
+
+/// Install the global `tracing` subscriber that backs [`Printer`].
+///
+/// No flag, `-v`, `-vv`, and `-vvv` map to `warn`/`info`/`debug`/`trace` the same way
+/// `--verbose` always has, but `RUST_LOG` overrides that mapping when set
+/// — useful for scoping a log level to one noisy module (uv's resolver,
+/// say) without cranking everything up. `--quiet` overrides both: it's
+/// the one invariant that must hold no matter what `RUST_LOG` says, since
+/// a user who asked for quiet output shouldn't have to fight an
+/// environment variable to get it. uv logs through `tracing` internally,
+/// so this same subscriber is what makes `-v` surface its resolver and
+/// installer diagnostics, not just ripenv's own messages.
+fn init_tracing(verbosity: u8, quiet: bool) {
+    let filter = if quiet {
+        EnvFilter::new("error")
+    } else {
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+            let level = match verbosity {
+                0 => "warn",
+                1 => "info",
+                2 => "debug",
+                _ => "trace",
+            };
+            EnvFilter::new(level)
+        })
+    };
+
+    // Best-effort: a second call (e.g. from a test harness that invokes
+    // `main` more than once in the same process) would otherwise panic.
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_target(false)
+        .without_time()
+        .with_writer(std::io::stderr)
+        .try_init();
+}
 
 /// Entry point for the ripenv CLI.
 ///
-/// Parses CLI arguments, sets up the tokio runtime on a dedicated thread
+/// Resolves any leading Pipfile-defined alias (see [`aliases::resolve`]),
+/// parses CLI arguments, sets up the tokio runtime on a dedicated thread
 /// (see [`min_stack_size`]), and dispatches to the appropriate command handler.
 pub fn main<I, T>(args: I) -> ExitCode
 where
     I: IntoIterator<Item = T>,
     T: Into<OsString> + Clone,
 {
-    let cli = match Cli::try_parse_from(args) {
+    let args: Vec<OsString> = args.into_iter().map(Into::into).collect();
+    let args = aliases::resolve(args);
+
+    let cli = match Cli::try_parse_from(args.clone()) {
         Ok(cli) => cli,
-        Err(err) => err.exit(),
+        Err(err) => {
+            err.print().ok();
+            if err.kind() == clap::error::ErrorKind::InvalidSubcommand {
+                if let Some(typed) = args.get(1).and_then(|s| s.to_str()) {
+                    if let Some(hint) = suggest::suggest(typed, &aliases::alias_names()) {
+                        eprintln!("  {}: did you mean `ripenv {hint}`?", "tip".cyan().bold());
+                    }
+                }
+            }
+            std::process::exit(err.exit_code());
+        }
     };
 
-    let printer = Printer::new(cli.verbose, cli.quiet);
+    init_tracing(cli.verbose, cli.quiet);
+    let printer = Printer::new(cli.verbose, cli.quiet, cli.format);
 
     // Run on a dedicated thread with a larger stack to match uv's convention.
     // See `min_stack_size` doc comment for rationale.
@@ -76,12 +132,11 @@ where
         Err(err) => {
             let mut causes = err.chain();
             // An anyhow::Error always has at least one cause (itself).
-            printer.error(
-                &causes
-                    .next()
-                    .expect("error chain is never empty")
-                    .to_string(),
-            );
+            let message = causes
+                .next()
+                .expect("error chain is never empty")
+                .to_string();
+            printer.error(&message);
             for cause in causes {
                 eprintln!(
                     "  {}: {}",
@@ -89,6 +144,7 @@ where
                     cause.to_string().trim()
                 );
             }
+            printer.emit_json(&commands::events::Event::Error { message });
             ExitStatus::Error.into()
         }
     }
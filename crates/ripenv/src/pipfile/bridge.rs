@@ -178,17 +178,26 @@ fn build_marker(detail: &PipfilePackageDetail) -> String {
 /// Build an inline TOML source table for `[tool.uv.sources]`.
 fn build_uv_source(detail: &PipfilePackageDetail) -> Option<String> {
     if let Some(ref git) = detail.git {
-        let mut parts = vec![format!("git = \"{git}\"")];
+        let mut parts = vec![format!("git = \"{}\"", escape_toml_string(git))];
         if let Some(ref git_ref) = detail.git_ref {
             // Map pipenv's generic `ref` to uv's `rev`
-            parts.push(format!("rev = \"{git_ref}\""));
+            parts.push(format!("rev = \"{}\"", escape_toml_string(git_ref)));
+        }
+        if let Some(ref subdirectory) = detail.subdirectory {
+            parts.push(format!(
+                "subdirectory = \"{}\"",
+                escape_toml_string(subdirectory)
+            ));
         }
         return Some(format!("{{ {} }}", parts.join(", ")));
     }
 
     if let Some(ref path) = detail.path {
-        let mut parts = vec![format!("path = \"{path}\"")];
-        if detail.editable {
+        let mut parts = vec![format!("path = \"{}\"", escape_toml_string(path))];
+        // Local archives (`.tar.gz`/`.zip`/`.whl`) can't be installed
+        // editable — there's no source tree for uv to link back to — so
+        // `editable = true` is only meaningful for directory paths.
+        if detail.editable && !is_local_archive(path) {
             parts.push("editable = true".to_owned());
         }
         return Some(format!("{{ {} }}", parts.join(", ")));
@@ -196,12 +205,26 @@ fn build_uv_source(detail: &PipfilePackageDetail) -> Option<String> {
 
     // Index-pinned packages: source is the index name
     if let Some(ref index) = detail.index {
-        return Some(format!("{{ index = \"{index}\" }}"));
+        return Some(format!("{{ index = \"{}\" }}", escape_toml_string(index)));
     }
 
     None
 }
 
+/// Whether a `path` spec points at a pre-built local archive rather than a
+/// source directory, e.g. `./dist/my-pkg-1.0.0.tar.gz` or `./vendor/pkg.zip`.
+///
+/// Pipenv installs these directly via pip's local-file support even though
+/// they never appear in the lockfile as a registry entry; uv's path source
+/// handles them the same way as a directory, minus the editable option.
+fn is_local_archive(path: &str) -> bool {
+    let lower = path.to_lowercase();
+    lower.ends_with(".tar.gz")
+        || lower.ends_with(".tgz")
+        || lower.ends_with(".zip")
+        || lower.ends_with(".whl")
+}
+
 /// Write a `[[tool.uv.index]]` entry for a Pipfile source.
 fn write_index_entry(toml: &mut String, source: &PipfileSource, is_first: bool) -> Result<()> {
     writeln!(toml, "[[tool.uv.index]]").context("failed to write index entry")?;
@@ -323,4 +346,66 @@ mod tests {
         let toml = format_simple_requirement("requests", ">=2.32.0");
         assert_eq!(toml, "requests>=2.32.0");
     }
+
+    #[test]
+    fn bridge_local_archive_is_not_editable() {
+        let detail = PipfilePackageDetail {
+            path: Some("./dist/my-pkg-1.0.0.tar.gz".to_owned()),
+            editable: true,
+            ..PipfilePackageDetail::default()
+        };
+
+        let source = build_uv_source(&detail).expect("archive path should produce a source");
+        assert!(source.contains("path = \"./dist/my-pkg-1.0.0.tar.gz\""));
+        assert!(!source.contains("editable"));
+    }
+
+    #[test]
+    fn bridge_git_source_includes_subdirectory() {
+        let detail = PipfilePackageDetail {
+            git: Some("https://github.com/example/monorepo.git".to_owned()),
+            git_ref: Some("v1.0.0".to_owned()),
+            subdirectory: Some("packages/my-lib".to_owned()),
+            ..PipfilePackageDetail::default()
+        };
+
+        let source = build_uv_source(&detail).expect("git detail should produce a source");
+        assert!(source.contains("git = \"https://github.com/example/monorepo.git\""));
+        assert!(source.contains("rev = \"v1.0.0\""));
+        assert!(source.contains("subdirectory = \"packages/my-lib\""));
+    }
+
+    #[test]
+    fn bridge_directory_path_stays_editable() {
+        let detail = PipfilePackageDetail {
+            path: Some("./local-pkg".to_owned()),
+            editable: true,
+            ..PipfilePackageDetail::default()
+        };
+
+        let source = build_uv_source(&detail).expect("directory path should produce a source");
+        assert!(source.contains("editable = true"));
+    }
+
+    #[test]
+    fn build_uv_source_escapes_quotes_and_backslashes() {
+        let detail = PipfilePackageDetail {
+            git: Some("https://example.com/repo.git".to_owned()),
+            git_ref: Some("feature/\"quoted\"".to_owned()),
+            subdirectory: Some(r"sub\dir".to_owned()),
+            ..PipfilePackageDetail::default()
+        };
+
+        let source = build_uv_source(&detail).expect("git detail should produce a source");
+        assert!(source.contains(r#"rev = "feature/\"quoted\"""#));
+        assert!(source.contains(r#"subdirectory = "sub\\dir""#));
+    }
+
+    #[test]
+    fn local_archive_detection() {
+        assert!(is_local_archive("./dist/pkg-1.0.0.tar.gz"));
+        assert!(is_local_archive("./vendor/PKG.ZIP"));
+        assert!(is_local_archive("./wheels/pkg-1.0.0-py3-none-any.whl"));
+        assert!(!is_local_archive("./local-pkg"));
+    }
 }
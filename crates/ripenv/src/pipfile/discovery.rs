@@ -7,6 +7,9 @@ use std::env;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Result, bail};
+use uv_python::PythonRequest;
+
+use crate::pipfile::model::PipfileRequires;
 
 /// Default maximum directory traversal depth.
 const DEFAULT_MAX_DEPTH: usize = 3;
@@ -14,6 +17,11 @@ const DEFAULT_MAX_DEPTH: usize = 3;
 /// The filename we're looking for.
 const PIPFILE_NAME: &str = "Pipfile";
 
+/// Filenames that pin an interpreter version for a project, checked in
+/// this order at each directory level (mirrors pyenv's `.python-version`
+/// plus uv's multi-version `.python-versions` file).
+const PYTHON_VERSION_FILE_NAMES: &[&str] = &[".python-version", ".python-versions"];
+
 /// Discover the Pipfile by walking up from the given directory.
 ///
 /// Resolution order:
@@ -59,6 +67,90 @@ pub fn project_root(pipfile_path: &Path) -> Option<&Path> {
     pipfile_path.parent()
 }
 
+/// Locate the nearest `ripenv.toml`, walking up from `start_dir` the same
+/// way [`find_pipfile`] does. Unlike the Pipfile, this file is optional —
+/// a miss is `None` rather than an error.
+pub fn find_ripenv_toml(start_dir: &Path) -> Option<PathBuf> {
+    let max_depth = env::var("PIPENV_MAX_DEPTH")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(DEFAULT_MAX_DEPTH);
+
+    let mut current = start_dir.to_path_buf();
+    for _ in 0..=max_depth {
+        let candidate = current.join("ripenv.toml");
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if !current.pop() {
+            break;
+        }
+    }
+    None
+}
+
+/// Locate the nearest `.python-version` (or `.python-versions`) file,
+/// starting at `start_dir` and ascending parent directories until a match
+/// is found.
+///
+/// Returns the first line of the file, trimmed, or `None` if no such file
+/// exists in `start_dir` or any ancestor.
+pub fn find_python_version_file(start_dir: &Path) -> Option<String> {
+    let mut current = start_dir.to_path_buf();
+    loop {
+        for name in PYTHON_VERSION_FILE_NAMES {
+            let candidate = current.join(name);
+            if !candidate.is_file() {
+                continue;
+            }
+            if let Ok(content) = fs_err::read_to_string(&candidate) {
+                if let Some(version) = content.lines().next().map(str::trim) {
+                    if !version.is_empty() {
+                        return Some(version.to_owned());
+                    }
+                }
+            }
+        }
+        if !current.pop() {
+            return None;
+        }
+    }
+}
+
+/// Resolve the `PythonRequest` ripenv should pass to uv's `lock`/`sync`/`run`.
+///
+/// Precedence (highest first):
+/// 1. `explicit` — an explicit CLI flag or `PIPENV_PYTHON` environment
+///    variable, passed in by the caller.
+/// 2. The nearest `.python-version`/`.python-versions` file, starting at
+///    `project_dir` and walking up.
+/// 3. The Pipfile's `[requires] python_version`/`python_full_version`.
+///
+/// Returns `None` if none of the above are present.
+pub fn resolve_python_request(
+    project_dir: &Path,
+    explicit: Option<&str>,
+    requires: Option<&PipfileRequires>,
+) -> Option<PythonRequest> {
+    if let Some(explicit) = explicit {
+        return Some(PythonRequest::parse(explicit));
+    }
+
+    if let Some(version) = find_python_version_file(project_dir) {
+        return Some(PythonRequest::parse(&version));
+    }
+
+    let requires = requires?;
+    if let Some(ref full_version) = requires.python_full_version {
+        return Some(PythonRequest::parse(full_version));
+    }
+    if let Some(ref version) = requires.python_version {
+        return Some(PythonRequest::parse(version));
+    }
+
+    None
+}
+
 /// Derive a project name from the project root directory.
 ///
 /// Falls back to `"project"` if the directory name can't be determined.
@@ -112,9 +204,119 @@ mod tests {
         assert_eq!(name, "my-project");
     }
 
+    #[test]
+    fn find_python_version_in_current_dir() {
+        let dir = TempDir::new().unwrap();
+        fs_err::write(dir.path().join(".python-version"), "3.12.1\n").unwrap();
+
+        let found = find_python_version_file(dir.path()).unwrap();
+        assert_eq!(found, "3.12.1");
+    }
+
+    #[test]
+    fn find_python_version_in_parent_dir() {
+        let dir = TempDir::new().unwrap();
+        fs_err::write(dir.path().join(".python-version"), "3.11\n").unwrap();
+
+        let subdir = dir.path().join("src");
+        fs_err::create_dir(&subdir).unwrap();
+
+        let found = find_python_version_file(&subdir).unwrap();
+        assert_eq!(found, "3.11");
+    }
+
+    #[test]
+    fn find_python_versions_plural_file() {
+        let dir = TempDir::new().unwrap();
+        fs_err::write(dir.path().join(".python-versions"), "3.12\n3.11\n").unwrap();
+
+        let found = find_python_version_file(dir.path()).unwrap();
+        assert_eq!(found, "3.12");
+    }
+
+    #[test]
+    fn no_python_version_file_returns_none() {
+        let dir = TempDir::new().unwrap();
+        assert!(find_python_version_file(dir.path()).is_none());
+    }
+
+    #[test]
+    fn resolve_python_request_prefers_explicit() {
+        let dir = TempDir::new().unwrap();
+        fs_err::write(dir.path().join(".python-version"), "3.11\n").unwrap();
+
+        let requires = PipfileRequires {
+            python_version: Some("3.10".to_owned()),
+            python_full_version: None,
+        };
+
+        let request = resolve_python_request(dir.path(), Some("3.13"), Some(&requires));
+        assert_eq!(request, Some(PythonRequest::parse("3.13")));
+    }
+
+    #[test]
+    fn resolve_python_request_prefers_version_file_over_requires() {
+        let dir = TempDir::new().unwrap();
+        fs_err::write(dir.path().join(".python-version"), "3.11\n").unwrap();
+
+        let requires = PipfileRequires {
+            python_version: Some("3.10".to_owned()),
+            python_full_version: None,
+        };
+
+        let request = resolve_python_request(dir.path(), None, Some(&requires));
+        assert_eq!(request, Some(PythonRequest::parse("3.11")));
+    }
+
+    #[test]
+    fn resolve_python_request_falls_back_to_requires() {
+        let dir = TempDir::new().unwrap();
+
+        let requires = PipfileRequires {
+            python_version: Some("3.10".to_owned()),
+            python_full_version: None,
+        };
+
+        let request = resolve_python_request(dir.path(), None, Some(&requires));
+        assert_eq!(request, Some(PythonRequest::parse("3.10")));
+    }
+
+    #[test]
+    fn resolve_python_request_none_when_nothing_set() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(resolve_python_request(dir.path(), None, None), None);
+    }
+
     #[test]
     fn project_name_fallback() {
         let name = project_name_from_dir(Path::new("/"));
         assert_eq!(name, "project");
     }
+
+    #[test]
+    fn find_ripenv_toml_in_current_dir() {
+        let dir = TempDir::new().unwrap();
+        let config = dir.path().join("ripenv.toml");
+        fs_err::write(&config, "[aliases]\n").unwrap();
+
+        assert_eq!(find_ripenv_toml(dir.path()), Some(config));
+    }
+
+    #[test]
+    fn find_ripenv_toml_in_parent_dir() {
+        let dir = TempDir::new().unwrap();
+        let config = dir.path().join("ripenv.toml");
+        fs_err::write(&config, "[aliases]\n").unwrap();
+
+        let subdir = dir.path().join("src");
+        fs_err::create_dir(&subdir).unwrap();
+
+        assert_eq!(find_ripenv_toml(&subdir), Some(config));
+    }
+
+    #[test]
+    fn no_ripenv_toml_returns_none() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(find_ripenv_toml(dir.path()), None);
+    }
 }
@@ -68,9 +68,22 @@ pub struct PipfileLockSource {
 }
 
 /// A locked package entry in the `default` or `develop` sections.
+///
+/// Mirrors pipenv's lockfile format, which uses alternate key sets to
+/// distinguish source kinds instead of a single tagged shape: a registry
+/// package gets `version`/`hashes`/`index`, a VCS dependency gets
+/// `git`/`ref` (no hashes, since the commit itself is the integrity
+/// anchor), a direct archive URL gets `file`/`hashes`, and a local or
+/// editable dependency gets `path`/`editable`. Fields are omitted via
+/// `skip_serializing_if` rather than modeled as an enum so that a single
+/// `#[derive(Serialize)]` produces pipenv-compatible flat JSON objects.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PipfileLockPackage {
     /// SHA256 hashes from all distributions (sdist + wheels).
+    ///
+    /// Omitted for git and local/editable dependencies, which have no
+    /// registry-style hashes to pin.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub hashes: Vec<String>,
     /// Source index name (only for registry packages).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -78,8 +91,42 @@ pub struct PipfileLockPackage {
     /// PEP 508 environment markers.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub markers: Option<String>,
-    /// Pinned version string (e.g., `"==1.2.3"`).
-    pub version: String,
+    /// Pinned version string (e.g., `"==1.2.3"`). Only set for registry
+    /// and direct-URL packages; `None` for git and local dependencies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Git remote URL, for VCS dependencies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git: Option<String>,
+    /// Resolved commit SHA, for VCS dependencies.
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub git_ref: Option<String>,
+    /// Direct archive URL, for non-VCS URL dependencies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Local filesystem path, for path/editable dependencies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Whether the local dependency is installed editable.
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub editable: bool,
+}
+
+impl PipfileLock {
+    /// Parse `Pipfile.lock` from `project_dir`, if one exists.
+    ///
+    /// Used to read back a lock for diffing (see
+    /// `crate::commands::update_plan`), as opposed to [`generate_pipfile_lock`]
+    /// which writes one from `uv.lock`.
+    pub fn read_from(project_dir: &Path) -> Result<Option<Self>> {
+        let path = project_dir.join("Pipfile.lock");
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let content = fs_err::read_to_string(&path).context("failed to read Pipfile.lock")?;
+        let lock = serde_json::from_str(&content).context("failed to parse Pipfile.lock")?;
+        Ok(Some(lock))
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -91,10 +138,19 @@ pub struct PipfileLockPackage {
 /// Reads `uv.lock` from `project_dir`, categorizes packages into
 /// `default` / `develop` based on the Pipfile dependency graph, and
 /// writes `Pipfile.lock` as deterministic JSON.
+///
+/// If `cache_dir` is set, registry/direct-URL packages that `uv.lock`
+/// resolved without a hash are backfilled by hashing whatever matching
+/// `.whl`/`.tar.gz` artifact is already sitting in uv's cache. If
+/// `strict_hashes` is set, generation fails with a clear error listing
+/// every package still missing a hash after backfill, instead of
+/// writing an under-hashed `Pipfile.lock`.
 pub fn generate_pipfile_lock(
     project_dir: &Path,
     pipfile: &Pipfile,
     printer: &Printer,
+    cache_dir: Option<&Path>,
+    strict_hashes: bool,
 ) -> Result<()> {
     let uv_lock_path = project_dir.join("uv.lock");
     if !uv_lock_path.is_file() {
@@ -111,9 +167,6 @@ pub fn generate_pipfile_lock(
         if package.is_virtual() {
             continue;
         }
-        if package.version().is_none() {
-            continue;
-        }
         let name = package.name().to_string();
         packages_by_name.entry(name).or_default().push(package);
     }
@@ -121,22 +174,76 @@ pub fn generate_pipfile_lock(
     // Walk the dependency graph to split default vs develop.
     let (default_names, develop_names) = categorize_packages(pipfile, &lock);
 
-    let build_entry = |package: &uv_resolver::Package| -> PipfileLockPackage {
-        let hashes: Vec<String> = package.hashes().iter().map(ToString::to_string).collect();
-
-        let version = package
-            .version()
-            .map(|v| format!("=={v}"))
-            .unwrap_or_default();
-
-        // Attempt to find the matching Pipfile source name for registry packages.
-        let index = find_source_name(package, project_dir, pipfile);
+    // Walk the same graph a second time, this time accumulating the PEP
+    // 508 marker under which each package is reachable, so conditional
+    // dependencies (e.g. `sys_platform == 'win32'`) survive into the lock.
+    let reachability_markers = collect_markers(pipfile, &lock);
+
+    let mut missing_hashes: Vec<String> = Vec::new();
+
+    let mut build_entry = |package: &uv_resolver::Package| -> PipfileLockPackage {
+        let mut hashes: Vec<String> = package.hashes().iter().map(ToString::to_string).collect();
+        let markers = reachability_markers
+            .get(package.name().as_ref())
+            .and_then(uv_pep508::MarkerTree::try_to_string);
+
+        let kind = source_kind(package);
+        let is_hashable = !matches!(kind, SourceKind::Git { .. } | SourceKind::Local { .. });
+        if is_hashable && hashes.is_empty() {
+            if let (Some(cache_dir), Some(version)) = (cache_dir, package.version()) {
+                hashes =
+                    backfill_hashes_from_cache(cache_dir, package.name().as_ref(), &version.to_string());
+            }
+            if hashes.is_empty() {
+                missing_hashes.push(package.name().to_string());
+            }
+        }
 
-        PipfileLockPackage {
-            hashes,
-            index,
-            markers: None,
-            version,
+        match kind {
+            SourceKind::Git { url, commit } => PipfileLockPackage {
+                hashes: Vec::new(),
+                index: None,
+                markers,
+                version: None,
+                git: Some(url),
+                git_ref: Some(commit),
+                file: None,
+                path: None,
+                editable: false,
+            },
+            SourceKind::Direct { url } => PipfileLockPackage {
+                hashes,
+                index: None,
+                markers,
+                version: package.version().map(|v| format!("=={v}")),
+                git: None,
+                git_ref: None,
+                file: Some(url),
+                path: None,
+                editable: false,
+            },
+            SourceKind::Local { path, editable } => PipfileLockPackage {
+                hashes: Vec::new(),
+                index: None,
+                markers,
+                version: None,
+                git: None,
+                git_ref: None,
+                file: None,
+                path: Some(path),
+                editable,
+            },
+            SourceKind::Registry => PipfileLockPackage {
+                hashes,
+                index: find_source_name(package, project_dir, pipfile),
+                markers,
+                version: package.version().map(|v| format!("=={v}")),
+                git: None,
+                git_ref: None,
+                file: None,
+                path: None,
+                editable: false,
+            },
         }
     };
 
@@ -162,6 +269,16 @@ pub fn generate_pipfile_lock(
         }
     }
 
+    if strict_hashes && !missing_hashes.is_empty() {
+        missing_hashes.sort();
+        missing_hashes.dedup();
+        anyhow::bail!(
+            "refusing to write an under-hashed Pipfile.lock (--require-hashes): \
+             missing hashes for {}",
+            missing_hashes.join(", ")
+        );
+    }
+
     let pipfile_lock = PipfileLock {
         meta: PipfileLockMeta {
             hash: PipfileLockHash {
@@ -338,6 +455,71 @@ fn categorize_packages(pipfile: &Pipfile, lock: &Lock) -> (BTreeSet<String>, BTr
     (default_names, develop_only)
 }
 
+/// Accumulate the PEP 508 marker under which each package is reachable
+/// from the Pipfile's `[packages]`/`[dev-packages]` roots.
+///
+/// Direct roots are unconditional (`MarkerTree::TRUE`). Each dependency
+/// edge ANDs its own marker onto the path it extends; when a package is
+/// reachable via more than one path, the paths are ORed together so the
+/// emitted marker captures every way the package can end up installed.
+/// A package with no marker restriction on any path ends up with a
+/// `MarkerTree` that simplifies to "always true", which `build_entry`
+/// treats as "omit `markers` entirely" via `MarkerTree::try_to_string`.
+fn collect_markers(pipfile: &Pipfile, lock: &Lock) -> BTreeMap<String, uv_pep508::MarkerTree> {
+    let mut adjacency: BTreeMap<String, Vec<(String, uv_pep508::MarkerTree)>> = BTreeMap::new();
+    for package in lock.packages() {
+        let name = package.name().to_string();
+        let edges = package
+            .dependencies()
+            .iter()
+            .map(|d| (d.package_name().to_string(), d.marker().clone()))
+            .collect();
+        adjacency.insert(name, edges);
+    }
+
+    let mut markers: BTreeMap<String, uv_pep508::MarkerTree> = BTreeMap::new();
+    let mut queue: VecDeque<String> = VecDeque::new();
+
+    for root in pipfile.packages.keys().chain(pipfile.dev_packages.keys()) {
+        let name = normalize_package_name(root);
+        markers.insert(name.clone(), uv_pep508::MarkerTree::TRUE);
+        queue.push_back(name);
+    }
+
+    while let Some(name) = queue.pop_front() {
+        let Some(current) = markers.get(&name).cloned() else {
+            continue;
+        };
+        let Some(edges) = adjacency.get(&name) else {
+            continue;
+        };
+        for (dep, edge_marker) in edges {
+            let mut combined = current.clone();
+            combined.and(edge_marker.clone());
+
+            let changed = match markers.get(dep) {
+                Some(existing) if existing == &combined || existing.is_true() => false,
+                Some(existing) => {
+                    let mut merged = existing.clone();
+                    merged.or(combined);
+                    let changed = merged != *existing;
+                    markers.insert(dep.clone(), merged);
+                    changed
+                }
+                None => {
+                    markers.insert(dep.clone(), combined);
+                    true
+                }
+            };
+            if changed {
+                queue.push_back(dep.clone());
+            }
+        }
+    }
+
+    markers
+}
+
 /// BFS reachability from a set of root package names.
 fn bfs_reachable(adjacency: &BTreeMap<String, Vec<String>>, roots: &[String]) -> BTreeSet<String> {
     let mut visited = BTreeSet::new();
@@ -369,6 +551,104 @@ fn normalize_package_name(name: &str) -> String {
         .unwrap_or_else(|_| name.to_lowercase().replace('_', "-"))
 }
 
+/// The kind of source a resolved `uv.lock` package came from.
+///
+/// Mirrors `uv_resolver`'s own `Source` distinction (registry / git /
+/// direct URL / local path), collapsed down to what pipenv's lockfile
+/// format needs to render.
+enum SourceKind {
+    /// A package pulled from a PyPI-compatible index.
+    Registry,
+    /// A package pinned to a git remote and resolved commit.
+    Git { url: String, commit: String },
+    /// A package pulled from a direct archive URL (sdist/wheel).
+    Direct { url: String },
+    /// A local path or editable install.
+    Local { path: String, editable: bool },
+}
+
+/// Classify a resolved package's source kind.
+fn source_kind(package: &uv_resolver::Package) -> SourceKind {
+    if let Some(git) = package.git() {
+        return SourceKind::Git {
+            url: git.repository().to_string(),
+            commit: git.precise().map_or_else(
+                || git.reference().to_string(),
+                |sha| sha.to_string(),
+            ),
+        };
+    }
+    if let Some(path) = package.install_path() {
+        return SourceKind::Local {
+            path: path.display().to_string(),
+            editable: package.is_editable(),
+        };
+    }
+    if let Some(url) = package.direct_url() {
+        return SourceKind::Direct {
+            url: url.to_string(),
+        };
+    }
+    SourceKind::Registry
+}
+
+/// Best-effort backfill for a registry/direct-URL package's missing hashes.
+///
+/// `uv.lock` can legitimately omit hashes for some resolutions. Rather
+/// than leave `hashes` empty, scan uv's cache directory for a
+/// `{name}-{version}` wheel or sdist already sitting on disk and hash
+/// it directly — mirroring how `npm`'s lockfile tooling reconciles
+/// hashes from its local cache instead of only trusting the resolver.
+/// Returns an empty `Vec` if no matching cached artifact is found.
+fn backfill_hashes_from_cache(cache_dir: &Path, name: &str, version: &str) -> Vec<String> {
+    let prefix = format!("{name}-{version}");
+    walk_cache_dir(cache_dir)
+        .into_iter()
+        .filter(|path| {
+            path.file_name()
+                .and_then(|f| f.to_str())
+                .is_some_and(|f| filename_matches_exact_version(f, &prefix))
+        })
+        .filter_map(|path| fs_err::read(&path).ok())
+        .map(|bytes| format!("sha256:{:x}", Sha256::digest(&bytes)))
+        .collect()
+}
+
+/// Whether `filename` is a wheel/sdist for exactly `{name}-{version}`
+/// (`prefix`), not just a release that happens to share that prefix.
+///
+/// A bare `starts_with` would let `six-1.16.0` match a cached
+/// `six-1.16.0.post1-py2.py3-none-any.whl`, silently backfilling the
+/// wrong release's hash — exactly the kind of version confusion
+/// `--require-hashes` exists to catch. The build tag/platform segment of
+/// a wheel always starts with `-` right after the version, and a sdist's
+/// version is immediately followed by `.tar.gz`, so requiring one of
+/// those right after `prefix` rules out same-prefix siblings.
+fn filename_matches_exact_version(filename: &str, prefix: &str) -> bool {
+    let Some(rest) = filename.strip_prefix(prefix) else {
+        return false;
+    };
+    (filename.ends_with(".whl") && rest.starts_with('-')) || rest == ".tar.gz"
+}
+
+/// Recursively enumerate files under `dir`. Best-effort: returns an
+/// empty list if `dir` doesn't exist or can't be read.
+fn walk_cache_dir(dir: &Path) -> Vec<std::path::PathBuf> {
+    let mut files = Vec::new();
+    let Ok(entries) = fs_err::read_dir(dir) else {
+        return files;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            files.extend(walk_cache_dir(&path));
+        } else {
+            files.push(path);
+        }
+    }
+    files
+}
+
 /// Attempt to find the Pipfile source name for a registry package.
 ///
 /// Matches the package's index URL against the Pipfile sources. Returns
@@ -459,6 +739,138 @@ mod tests {
         assert_eq!(reachable.len(), 4);
     }
 
+    #[test]
+    fn test_registry_entry_omits_vcs_fields() {
+        let entry = PipfileLockPackage {
+            hashes: vec!["sha256:abc".to_owned()],
+            index: Some("pypi".to_owned()),
+            markers: None,
+            version: Some("==2.0.0".to_owned()),
+            git: None,
+            git_ref: None,
+            file: None,
+            path: None,
+            editable: false,
+        };
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["version"], "==2.0.0");
+        assert!(json.get("git").is_none());
+        assert!(json.get("ref").is_none());
+        assert!(json.get("path").is_none());
+        assert!(json.get("editable").is_none());
+    }
+
+    #[test]
+    fn test_git_entry_omits_hashes_and_version() {
+        let entry = PipfileLockPackage {
+            hashes: Vec::new(),
+            index: None,
+            markers: None,
+            version: None,
+            git: Some("https://github.com/example/pkg.git".to_owned()),
+            git_ref: Some("abc1234".to_owned()),
+            file: None,
+            path: None,
+            editable: false,
+        };
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["git"], "https://github.com/example/pkg.git");
+        assert_eq!(json["ref"], "abc1234");
+        assert!(json.get("hashes").is_none());
+        assert!(json.get("version").is_none());
+    }
+
+    #[test]
+    fn test_editable_path_entry() {
+        let entry = PipfileLockPackage {
+            hashes: Vec::new(),
+            index: None,
+            markers: None,
+            version: None,
+            git: None,
+            git_ref: None,
+            file: None,
+            path: Some("./vendor/pkg".to_owned()),
+            editable: true,
+        };
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["path"], "./vendor/pkg");
+        assert_eq!(json["editable"], true);
+    }
+
+    #[test]
+    fn test_entry_with_markers_serializes_string() {
+        let entry = PipfileLockPackage {
+            hashes: vec!["sha256:abc".to_owned()],
+            index: Some("pypi".to_owned()),
+            markers: Some("sys_platform == 'win32'".to_owned()),
+            version: Some("==1.0.0".to_owned()),
+            git: None,
+            git_ref: None,
+            file: None,
+            path: None,
+            editable: false,
+        };
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["markers"], "sys_platform == 'win32'");
+    }
+
+    #[test]
+    fn test_backfill_hashes_from_cache_finds_wheel() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let wheel_dir = dir.path().join("wheels-v2").join("pypi");
+        fs_err::create_dir_all(&wheel_dir).unwrap();
+        fs_err::write(wheel_dir.join("six-1.16.0-py2.py3-none-any.whl"), b"fake wheel bytes").unwrap();
+
+        let hashes = backfill_hashes_from_cache(dir.path(), "six", "1.16.0");
+        assert_eq!(hashes.len(), 1);
+        assert!(hashes[0].starts_with("sha256:"));
+    }
+
+    #[test]
+    fn test_backfill_hashes_from_cache_no_match() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs_err::create_dir_all(dir.path()).unwrap();
+
+        let hashes = backfill_hashes_from_cache(dir.path(), "six", "1.16.0");
+        assert!(hashes.is_empty());
+    }
+
+    #[test]
+    fn test_backfill_hashes_from_cache_rejects_same_prefix_sibling_release() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let wheel_dir = dir.path().join("wheels-v2").join("pypi");
+        fs_err::create_dir_all(&wheel_dir).unwrap();
+        // A cached `1.16.0.post1` wheel shares the `six-1.16.0` prefix with
+        // the `1.16.0` release we're looking for, but is a different
+        // release and must not be mistaken for it.
+        fs_err::write(
+            wheel_dir.join("six-1.16.0.post1-py2.py3-none-any.whl"),
+            b"fake wheel bytes",
+        )
+        .unwrap();
+
+        let hashes = backfill_hashes_from_cache(dir.path(), "six", "1.16.0");
+        assert!(hashes.is_empty());
+    }
+
+    #[test]
+    fn test_filename_matches_exact_version() {
+        assert!(filename_matches_exact_version(
+            "six-1.16.0-py2.py3-none-any.whl",
+            "six-1.16.0"
+        ));
+        assert!(filename_matches_exact_version("six-1.16.0.tar.gz", "six-1.16.0"));
+        assert!(!filename_matches_exact_version(
+            "six-1.16.0.post1-py2.py3-none-any.whl",
+            "six-1.16.0"
+        ));
+        assert!(!filename_matches_exact_version(
+            "six-1.16.0.post1.tar.gz",
+            "six-1.16.0"
+        ));
+    }
+
     #[test]
     fn test_bfs_reachable_disjoint() {
         let mut adjacency = BTreeMap::new();
@@ -478,4 +890,58 @@ mod tests {
         assert!(develop.contains("pluggy"));
         assert!(!develop.contains("flask"));
     }
+
+    #[test]
+    fn generate_pipfile_lock_strict_hashes_errors_on_missing_hash() {
+        let project_dir = tempfile::TempDir::new().unwrap();
+        // Empty, so `backfill_hashes_from_cache` can't find a matching
+        // wheel/sdist and the package stays unhashed.
+        let empty_cache_dir = tempfile::TempDir::new().unwrap();
+
+        fs_err::write(
+            project_dir.path().join("uv.lock"),
+            r#"version = 1
+requires-python = ">=3.12"
+
+[[package]]
+name = "offlinepkg"
+version = "1.0.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/source/o/offlinepkg/offlinepkg-1.0.0.tar.gz" }
+"#,
+        )
+        .unwrap();
+
+        let pipfile: Pipfile = toml::from_str(
+            r#"[[source]]
+url = "https://pypi.org/simple"
+verify_ssl = true
+name = "pypi"
+
+[packages]
+offlinepkg = "==1.0.0"
+
+[dev-packages]
+"#,
+        )
+        .unwrap();
+
+        let printer = Printer::new(0, false, crate::cli::OutputFormat::Human);
+        let err = generate_pipfile_lock(
+            project_dir.path(),
+            &pipfile,
+            &printer,
+            Some(empty_cache_dir.path()),
+            true,
+        )
+        .unwrap_err();
+
+        assert!(
+            err.to_string().contains("offlinepkg"),
+            "expected error to name the package missing a hash, got: {err}"
+        );
+
+        // No lockfile should've been written on the strict-hash failure.
+        assert!(!project_dir.path().join("Pipfile.lock").is_file());
+    }
 }
@@ -21,5 +21,14 @@ pub mod model;
 mod writer;
 
 pub use bridge::pipfile_to_pyproject_toml;
-pub use discovery::{find_pipfile, project_name_from_dir, project_root};
+pub use discovery::{
+    find_pipfile, find_python_version_file, project_name_from_dir, project_root,
+    resolve_python_request,
+};
 pub use model::Pipfile;
+
+/// Re-exported so sibling modules outside `pipfile` (e.g.
+/// [`crate::global::manifest`]) can serialize a [`model::PipfilePackage`]
+/// to `toml_edit` the same way the Pipfile writer does, without
+/// duplicating the field-by-field mapping.
+pub(crate) use writer::package_to_item;
@@ -30,11 +30,16 @@ pub struct Pipfile {
 
     /// Script definitions.
     #[serde(default)]
-    pub scripts: BTreeMap<String, String>,
+    pub scripts: BTreeMap<String, PipfileScript>,
 
     /// Pipenv-specific settings.
     #[serde(default)]
     pub pipenv: Option<PipfileSettings>,
+
+    /// User-defined command aliases (e.g. `ci = "install --deploy --no-dev"`),
+    /// resolved before clap subcommand dispatch. See [`crate::aliases`].
+    #[serde(default)]
+    pub aliases: BTreeMap<String, PipfileAlias>,
 }
 
 impl Pipfile {
@@ -98,6 +103,10 @@ pub struct PipfilePackageDetail {
     #[serde(rename = "ref")]
     pub git_ref: Option<String>,
 
+    /// Subdirectory within the git repository or archive containing the
+    /// package to build, for monorepo-style VCS checkouts.
+    pub subdirectory: Option<String>,
+
     /// Local path to a package.
     pub path: Option<String>,
 
@@ -107,6 +116,72 @@ pub struct PipfilePackageDetail {
 
     /// Specific index to install from.
     pub index: Option<String>,
+
+    /// `--hash=sha256:...` values collected while importing a
+    /// requirements.txt, preserved so round-tripping through the Pipfile
+    /// doesn't silently drop pinned hashes.
+    #[serde(default)]
+    pub hashes: Vec<String>,
+}
+
+/// A `[scripts]` entry.
+///
+/// Can be a bare command line (`test = "pytest -vvs"`) or a table with a
+/// base `cmd`, optional `env` variables to export before running, and
+/// optional per-OS overrides keyed on `std::env::consts::OS` (`"linux"`,
+/// `"macos"`, `"windows"`) that take precedence over `cmd` on a matching
+/// platform (`start = {cmd = "...", windows = "..."}`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum PipfileScript {
+    /// Bare command line.
+    Simple(String),
+
+    /// Table with a base command plus env/platform overrides.
+    Detailed(PipfileScriptDetail),
+}
+
+impl PipfileScript {
+    /// The command line to run on the current platform: a platform
+    /// override if one matches `std::env::consts::OS`, otherwise the base
+    /// `cmd` (or the bare command line for a [`Self::Simple`] script).
+    pub fn command(&self) -> &str {
+        match self {
+            Self::Simple(command) => command,
+            Self::Detailed(detail) => detail
+                .platforms
+                .get(std::env::consts::OS)
+                .unwrap_or(&detail.cmd),
+        }
+    }
+
+    /// Environment variables to export before running this script.
+    /// Empty for a [`Self::Simple`] script.
+    pub fn env(&self) -> &BTreeMap<String, String> {
+        static EMPTY: BTreeMap<String, String> = BTreeMap::new();
+        match self {
+            Self::Simple(_) => &EMPTY,
+            Self::Detailed(detail) => &detail.env,
+        }
+    }
+}
+
+/// Extended script specification fields.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PipfileScriptDetail {
+    /// Base command line, used when no platform-specific override matches
+    /// the current platform.
+    pub cmd: String,
+
+    /// Environment variables to export before running the command.
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+
+    /// Per-OS command overrides, keyed on `std::env::consts::OS` (e.g.
+    /// `"linux"`, `"macos"`, `"windows"`). Any table key other than
+    /// `cmd`/`env` is captured here.
+    #[serde(flatten)]
+    pub platforms: BTreeMap<String, String>,
 }
 
 /// The `[requires]` section of a Pipfile.
@@ -119,6 +194,31 @@ pub struct PipfileRequires {
     pub python_full_version: Option<String>,
 }
 
+/// An `[aliases]` entry.
+///
+/// Can be written as a whitespace-split command line (`ci = "install
+/// --deploy --no-dev"`) or an explicit argv list (`ci = ["install",
+/// "--deploy", "--no-dev"]`) for arguments containing spaces.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum PipfileAlias {
+    /// Whitespace-split command line.
+    Line(String),
+
+    /// Explicit argv list.
+    Args(Vec<String>),
+}
+
+impl PipfileAlias {
+    /// Expand this alias into its argv form.
+    pub fn expand(&self) -> Vec<String> {
+        match self {
+            Self::Line(line) => line.split_whitespace().map(str::to_owned).collect(),
+            Self::Args(args) => args.clone(),
+        }
+    }
+}
+
 /// The `[pipenv]` section for pipenv-specific settings.
 #[derive(Debug, Deserialize)]
 pub struct PipfileSettings {
@@ -7,9 +7,12 @@
 use std::fmt::Write;
 use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
-use crate::pipfile::model::{Pipfile, PipfilePackage, PipfilePackageDetail};
+use crate::pipfile::model::{
+    Pipfile, PipfilePackage, PipfilePackageDetail, PipfileScript, PipfileScriptDetail,
+    PipfileSource,
+};
 
 impl Pipfile {
     /// Write the Pipfile to the given path.
@@ -57,8 +60,17 @@ impl Pipfile {
         // [scripts]
         if !self.scripts.is_empty() {
             writeln!(out, "[scripts]")?;
-            for (name, command) in &self.scripts {
-                writeln!(out, "{name} = \"{}\"", escape_toml_value(command))?;
+            for (name, script) in &self.scripts {
+                match script {
+                    PipfileScript::Simple(command) => {
+                        writeln!(out, "{name} = \"{}\"", escape_toml_value(command))?;
+                    }
+                    PipfileScript::Detailed(detail) => {
+                        write!(out, "{name} = {{")?;
+                        write_script_detail_fields(&mut out, detail)?;
+                        writeln!(out, "}}")?;
+                    }
+                }
             }
             writeln!(out)?;
         }
@@ -74,6 +86,123 @@ impl Pipfile {
 
         Ok(out)
     }
+
+    /// Apply surgical edits to an on-disk Pipfile via `toml_edit`: append any
+    /// newly-registered `[[source]]` entries and upsert specific
+    /// `[packages]`/`[dev-packages]` keys, leaving every other byte —
+    /// comments, key order, blank-line grouping, inline-table style —
+    /// untouched.
+    ///
+    /// Falls back to [`Self::write_to`] (a full from-scratch rewrite) when
+    /// `path` doesn't exist yet; there's nothing to preserve for a file being
+    /// created for the first time.
+    pub fn apply_edits(
+        &self,
+        path: &Path,
+        new_sources: &[&PipfileSource],
+        package_entries: &[(String, bool)],
+    ) -> Result<()> {
+        if !path.is_file() {
+            return self.write_to(path);
+        }
+
+        let content =
+            fs_err::read_to_string(path).context("failed to read Pipfile for in-place edit")?;
+        let mut doc = content
+            .parse::<toml_edit::DocumentMut>()
+            .context("failed to parse Pipfile for in-place edit")?;
+
+        for source in new_sources {
+            let mut table = toml_edit::Table::new();
+            table["url"] = toml_edit::value(source.url.as_str());
+            table["verify_ssl"] = toml_edit::value(source.verify_ssl);
+            table["name"] = toml_edit::value(source.name.as_str());
+
+            doc.entry("source")
+                .or_insert_with(|| toml_edit::Item::ArrayOfTables(toml_edit::ArrayOfTables::new()))
+                .as_array_of_tables_mut()
+                .context("Pipfile [[source]] must be an array of tables")?
+                .push(table);
+        }
+
+        for (name, dev) in package_entries {
+            let table_key = if *dev { "dev-packages" } else { "packages" };
+            let source_map = if *dev {
+                &self.dev_packages
+            } else {
+                &self.packages
+            };
+            let Some(package) = source_map.get(name) else {
+                continue;
+            };
+
+            let table = doc
+                .entry(table_key)
+                .or_insert_with(|| toml_edit::Item::Table(toml_edit::Table::new()))
+                .as_table_mut()
+                .with_context(|| format!("Pipfile [{table_key}] must be a table"))?;
+
+            table.insert(name, package_to_item(package));
+        }
+
+        fs_err::write(path, doc.to_string()).context("failed to write Pipfile")?;
+        Ok(())
+    }
+}
+
+/// Convert a single Pipfile package into a `toml_edit` item, for surgical
+/// insertion into an existing document.
+///
+/// `pub(crate)` so sibling manifest writers (e.g.
+/// [`crate::global::manifest`]) that reuse [`PipfilePackage`] for their own
+/// TOML format can serialize entries the same way `Pipfile` does.
+pub(crate) fn package_to_item(package: &PipfilePackage) -> toml_edit::Item {
+    match package {
+        PipfilePackage::Simple(version) => toml_edit::value(version.as_str()),
+        PipfilePackage::Detailed(detail) => {
+            let mut table = toml_edit::InlineTable::new();
+
+            if let Some(ref version) = detail.version {
+                table.insert("version", version.as_str().into());
+            }
+            if !detail.extras.is_empty() {
+                let mut extras = toml_edit::Array::new();
+                extras.extend(detail.extras.iter().map(String::as_str));
+                table.insert("extras", extras.into());
+            }
+            if let Some(ref git) = detail.git {
+                table.insert("git", git.as_str().into());
+            }
+            if let Some(ref git_ref) = detail.git_ref {
+                table.insert("ref", git_ref.as_str().into());
+            }
+            if let Some(ref subdirectory) = detail.subdirectory {
+                table.insert("subdirectory", subdirectory.as_str().into());
+            }
+            if let Some(ref path) = detail.path {
+                table.insert("path", path.as_str().into());
+            }
+            if detail.editable {
+                table.insert("editable", true.into());
+            }
+            if let Some(ref index) = detail.index {
+                table.insert("index", index.as_str().into());
+            }
+            if let Some(ref markers) = detail.markers {
+                table.insert("markers", markers.as_str().into());
+            }
+            if let Some(ref sys_platform) = detail.sys_platform {
+                table.insert("sys_platform", sys_platform.as_str().into());
+            }
+            if !detail.hashes.is_empty() {
+                let mut hashes = toml_edit::Array::new();
+                hashes.extend(detail.hashes.iter().map(String::as_str));
+                table.insert("hashes", hashes.into());
+            }
+
+            toml_edit::Item::Value(toml_edit::Value::InlineTable(table))
+        }
+    }
 }
 
 /// Write a map of packages to TOML.
@@ -113,6 +242,9 @@ fn write_detail_fields(out: &mut String, detail: &PipfilePackageDetail) -> Resul
     if let Some(ref git_ref) = detail.git_ref {
         fields.push(format!("ref = \"{git_ref}\""));
     }
+    if let Some(ref subdirectory) = detail.subdirectory {
+        fields.push(format!("subdirectory = \"{subdirectory}\""));
+    }
     if let Some(ref path) = detail.path {
         fields.push(format!("path = \"{path}\""));
     }
@@ -128,6 +260,31 @@ fn write_detail_fields(out: &mut String, detail: &PipfilePackageDetail) -> Resul
     if let Some(ref sys_platform) = detail.sys_platform {
         fields.push(format!("sys_platform = \"{sys_platform}\""));
     }
+    if !detail.hashes.is_empty() {
+        let hashes: Vec<_> = detail.hashes.iter().map(|h| format!("\"{h}\"")).collect();
+        fields.push(format!("hashes = [{}]", hashes.join(", ")));
+    }
+
+    write!(out, "{}", fields.join(", "))?;
+    Ok(())
+}
+
+/// Write the inline table fields for a detailed script spec.
+fn write_script_detail_fields(out: &mut String, detail: &PipfileScriptDetail) -> Result<()> {
+    let mut fields = vec![format!("cmd = \"{}\"", escape_toml_value(&detail.cmd))];
+
+    if !detail.env.is_empty() {
+        let env: Vec<String> = detail
+            .env
+            .iter()
+            .map(|(key, value)| format!("{key} = \"{}\"", escape_toml_value(value)))
+            .collect();
+        fields.push(format!("env = {{{}}}", env.join(", ")));
+    }
+
+    for (platform, command) in &detail.platforms {
+        fields.push(format!("{platform} = \"{}\"", escape_toml_value(command)));
+    }
 
     write!(out, "{}", fields.join(", "))?;
     Ok(())
@@ -166,12 +323,63 @@ mod tests {
 
     #[test]
     fn round_trip_with_scripts() {
+        use crate::pipfile::model::PipfileScript;
+
         let pipfile = Pipfile::from_path(&fixture("with-scripts")).unwrap();
         let toml = pipfile.to_toml_string().unwrap();
 
         let reparsed: Pipfile = toml::from_str(&toml).unwrap();
         assert_eq!(reparsed.scripts.len(), 3);
-        assert_eq!(reparsed.scripts["test"], "pytest -vvs");
+        assert!(matches!(&reparsed.scripts["test"], PipfileScript::Simple(cmd) if cmd == "pytest -vvs"));
+    }
+
+    #[test]
+    fn round_trip_script_with_env() {
+        use crate::pipfile::model::{Pipfile, PipfileScript};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("Pipfile");
+        fs_err::write(
+            &path,
+            "[packages]\n\n[dev-packages]\n\n[scripts]\n\
+             test = {cmd = \"pytest -vvs\", env = {DEBUG = \"1\"}}\n",
+        )
+        .unwrap();
+
+        let pipfile = Pipfile::from_path(&path).unwrap();
+        let toml = pipfile.to_toml_string().unwrap();
+        let reparsed: Pipfile = toml::from_str(&toml).unwrap();
+
+        let PipfileScript::Detailed(detail) = &reparsed.scripts["test"] else {
+            panic!("expected a detailed script entry");
+        };
+        assert_eq!(detail.cmd, "pytest -vvs");
+        assert_eq!(detail.env["DEBUG"], "1");
+    }
+
+    #[test]
+    fn round_trip_script_with_platform_variant() {
+        use crate::pipfile::model::{Pipfile, PipfileScript};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("Pipfile");
+        fs_err::write(
+            &path,
+            "[packages]\n\n[dev-packages]\n\n[scripts]\n\
+             start = {cmd = \"python app.py\", windows = \"python.exe app.py\"}\n",
+        )
+        .unwrap();
+
+        let pipfile = Pipfile::from_path(&path).unwrap();
+        let toml = pipfile.to_toml_string().unwrap();
+        let reparsed: Pipfile = toml::from_str(&toml).unwrap();
+
+        let PipfileScript::Detailed(detail) = &reparsed.scripts["start"] else {
+            panic!("expected a detailed script entry");
+        };
+        assert_eq!(detail.cmd, "python app.py");
+        assert_eq!(detail.platforms["windows"], "python.exe app.py");
+        assert_eq!(reparsed.scripts["start"].command(), "python app.py");
     }
 
     #[test]
@@ -196,4 +404,81 @@ mod tests {
         assert_eq!(reparsed.source.len(), 1);
         assert_eq!(reparsed.packages.len(), 1);
     }
+
+    #[test]
+    fn apply_edits_preserves_comments_and_inserts_package() {
+        use crate::pipfile::model::{Pipfile, PipfilePackage};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("Pipfile");
+        fs_err::write(
+            &path,
+            "# top-level comment, should survive\n\
+             [[source]]\n\
+             url = \"https://pypi.org/simple\"\n\
+             verify_ssl = true\n\
+             name = \"pypi\"\n\n\
+             [packages]\n\
+             # a comment pinned to requests\n\
+             requests = \"*\"\n\n\
+             [dev-packages]\n",
+        )
+        .unwrap();
+
+        let mut pipfile = Pipfile::from_path(&path).unwrap();
+        pipfile
+            .packages
+            .insert("flask".to_owned(), PipfilePackage::Simple(">=3.0".to_owned()));
+
+        pipfile.apply_edits(&path, &[], &[("flask".to_owned(), false)]).unwrap();
+
+        let content = fs_err::read_to_string(&path).unwrap();
+        assert!(content.contains("# top-level comment, should survive"));
+        assert!(content.contains("# a comment pinned to requests"));
+        assert!(content.contains("flask = \">=3.0\""));
+
+        let reparsed = Pipfile::from_path(&path).unwrap();
+        assert_eq!(reparsed.packages.len(), 2);
+    }
+
+    #[test]
+    fn apply_edits_appends_new_source() {
+        use crate::pipfile::model::{Pipfile, PipfileSource};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("Pipfile");
+        fs_err::write(
+            &path,
+            "[[source]]\nurl = \"https://pypi.org/simple\"\nverify_ssl = true\nname = \"pypi\"\n\n[packages]\n\n[dev-packages]\n",
+        )
+        .unwrap();
+
+        let mut pipfile = Pipfile::from_path(&path).unwrap();
+        let new_source = PipfileSource {
+            name: "private".to_owned(),
+            url: "https://private.example.com/simple".to_owned(),
+            verify_ssl: true,
+        };
+        pipfile.source.push(new_source);
+        let new_sources: Vec<&PipfileSource> = vec![&pipfile.source[1]];
+
+        pipfile.apply_edits(&path, &new_sources, &[]).unwrap();
+
+        let reparsed = Pipfile::from_path(&path).unwrap();
+        assert_eq!(reparsed.source.len(), 2);
+        assert!(reparsed.source.iter().any(|s| s.name == "private"));
+    }
+
+    #[test]
+    fn apply_edits_falls_back_to_full_write_when_missing() {
+        let pipfile = Pipfile::from_path(&fixture("minimal")).unwrap();
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("Pipfile");
+
+        pipfile.apply_edits(&path, &[], &[]).unwrap();
+
+        let reparsed = Pipfile::from_path(&path).unwrap();
+        assert_eq!(reparsed.packages.len(), pipfile.packages.len());
+    }
 }
@@ -1,11 +1,28 @@
 //! Output formatting for ripenv commands.
 //!
-//! The [`Printer`] controls whether messages are emitted to stderr based on
-//! the user's `--quiet` and `--verbose` flags. Errors are always printed
-//! regardless of quiet mode (matching uv's behavior).
+//! The [`Printer`] controls whether messages are emitted, based on the
+//! user's `--quiet` and `--verbose` flags. Following the stdout/stderr
+//! discipline rye and uv use: ordinary informational/success output
+//! ([`Printer::info`]) goes to stdout so it can be piped, while
+//! diagnostics ([`Printer::warn`], [`Printer::debug`]) go through
+//! `tracing` to stderr — see [`crate::init_tracing`], which is also where
+//! uv's own internal `tracing` spans and events are routed, so `-v`/`-vv`
+//! surfaces uv's resolver/installer diagnostics, not just ripenv's own.
+//! [`Printer::error`] bypasses `tracing` entirely and writes straight to
+//! stderr, so it can never be filtered out by an `EnvFilter` (including
+//! the quiet one), and is always printed regardless of quiet mode
+//! (matching uv's behavior).
+//!
+//! Under `--format json` ([`Printer::is_json`]), `info` is rerouted to
+//! `tracing` (stderr) instead of stdout, so stdout stays reserved for
+//! [`Printer::emit_json`]'s NDJSON — see the module docs there.
+use std::io::Write;
 
-use anstream::eprintln;
+use anstream::{eprintln, println};
 use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use crate::cli::OutputFormat;
 
 /// Controls output formatting for ripenv commands.
 #[derive(Copy, Clone)]
@@ -14,40 +31,85 @@ pub struct Printer {
     verbosity: u8,
     /// Whether output is suppressed.
     quiet: bool,
+    /// Human prose (the default) or NDJSON events on stdout.
+    format: OutputFormat,
 }
 
 impl Printer {
-    /// Create a new printer with the given verbosity and quiet settings.
-    pub fn new(verbosity: u8, quiet: bool) -> Self {
-        Self { verbosity, quiet }
+    /// Create a new printer with the given verbosity, quiet, and format settings.
+    pub fn new(verbosity: u8, quiet: bool, format: OutputFormat) -> Self {
+        Self {
+            verbosity,
+            quiet,
+            format,
+        }
+    }
+
+    /// Whether this printer is in `--format json` mode.
+    pub fn is_json(&self) -> bool {
+        matches!(self.format, OutputFormat::Json)
+    }
+
+    /// Emit one NDJSON line to stdout.
+    ///
+    /// A no-op outside `--format json`, so call sites can call this
+    /// unconditionally alongside their usual `info`/`error` call rather
+    /// than branching on the format themselves. Bypasses the `tracing`
+    /// subscriber entirely — JSON events aren't log lines, they're the
+    /// command's actual output, and they belong on stdout, not stderr.
+    pub fn emit_json<T: Serialize>(&self, event: &T) {
+        if !self.is_json() {
+            return;
+        }
+        if let Ok(line) = serde_json::to_string(event) {
+            let _ = writeln!(std::io::stdout(), "{line}");
+        }
     }
 
-    /// Print an informational message to stderr.
+    /// Print an informational or success message to stdout.
+    ///
+    /// This is for results, not diagnostics — e.g. "Sync complete." or a
+    /// package diff summary — so scripts can pipe `ripenv`'s stdout
+    /// without picking up warnings or debug noise. Under `--format json`
+    /// it's rerouted through `tracing` (stderr) instead, since stdout is
+    /// reserved for [`Self::emit_json`] there.
     pub fn info(&self, message: &str) {
-        if !self.quiet {
-            eprintln!("{}", message);
+        if self.quiet {
+            return;
+        }
+        if self.is_json() {
+            tracing::info!("{message}");
+        } else {
+            println!("{message}");
         }
     }
 
-    /// Print a warning message to stderr.
+    /// Print a warning message.
     pub fn warn(&self, message: &str) {
         if !self.quiet {
-            eprintln!("{}: {}", "warning".yellow().bold(), message);
+            tracing::warn!("{}: {}", "warning".yellow().bold(), message);
         }
     }
 
     /// Print an error message to stderr.
     ///
     /// Errors are always printed, even in quiet mode, because suppressing
-    /// error output would hide actionable failures from the user.
+    /// error output would hide actionable failures from the user. Written
+    /// directly rather than through `tracing`, so no `EnvFilter` — not
+    /// even the quiet layer's `error`-only filter — can ever drop it.
     pub fn error(&self, message: &str) {
         eprintln!("{}: {}", "error".red().bold(), message);
     }
 
-    /// Print a debug message (only at verbosity >= 1).
+    /// Print a debug message (only at verbosity >= 2, i.e. `-vv`).
+    ///
+    /// Matches `init_tracing`'s verbosity→`EnvFilter` mapping, where `-v`
+    /// only raises the level to `info` and `-vv` is what unlocks `debug` —
+    /// gating here at `-v` would queue a `tracing::debug!` call that the
+    /// subscriber silently drops, so `-v` would look like it did nothing.
     pub fn debug(&self, message: &str) {
-        if self.verbosity >= 1 && !self.quiet {
-            eprintln!("{}: {}", "debug".dimmed(), message);
+        if self.verbosity >= 2 && !self.quiet {
+            tracing::debug!("{}: {}", "debug".dimmed(), message);
         }
     }
 }
@@ -0,0 +1,118 @@
+//! "Did you mean" suggestions for unrecognized subcommands.
+//!
+//! Mirrors cargo's typo correction: compute the Levenshtein distance
+//! between what the user typed and every known command name (including
+//! clap aliases like `upgrade` for `update`, and Pipfile-defined
+//! aliases), and suggest the closest one if it's close enough to
+//! plausibly be a typo rather than a genuinely unrelated command.
+
+use std::collections::HashSet;
+
+use clap::CommandFactory;
+
+use crate::cli::Cli;
+
+/// Maximum edit distance (or a third of the input length, if larger)
+/// for a suggestion to be considered "close enough" to be worth showing.
+const MAX_DISTANCE: usize = 3;
+
+/// Classic dynamic-programming Levenshtein distance between `a` and `b`,
+/// using two row buffers instead of a full `O(n*m)` matrix.
+fn lev_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.is_empty() {
+        return b.len();
+    }
+    if b.is_empty() {
+        return a.len();
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// All known top-level subcommand names, including clap aliases
+/// (e.g. `upgrade` for `update`) and any Pipfile-defined aliases.
+fn known_commands(pipfile_aliases: &HashSet<String>) -> Vec<String> {
+    let command = Cli::command();
+    let mut names: Vec<String> = Vec::new();
+    for sub in command.get_subcommands() {
+        names.push(sub.get_name().to_owned());
+        names.extend(sub.get_all_aliases().map(str::to_owned));
+    }
+    names.extend(pipfile_aliases.iter().cloned());
+    names
+}
+
+/// Find the closest known command to `typed`, if it's close enough to
+/// plausibly be a typo (within [`MAX_DISTANCE`] edits, or a third of
+/// `typed`'s length, whichever is larger).
+pub fn suggest(typed: &str, pipfile_aliases: &HashSet<String>) -> Option<String> {
+    let threshold = MAX_DISTANCE.max(typed.chars().count() / 3);
+
+    known_commands(pipfile_aliases)
+        .into_iter()
+        .map(|name| (lev_distance(typed, &name), name))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lev_distance_identical() {
+        assert_eq!(lev_distance("install", "install"), 0);
+    }
+
+    #[test]
+    fn lev_distance_single_substitution() {
+        assert_eq!(lev_distance("insta1l", "install"), 1);
+    }
+
+    #[test]
+    fn lev_distance_insertion() {
+        assert_eq!(lev_distance("instal", "install"), 1);
+    }
+
+    #[test]
+    fn suggest_finds_close_typo() {
+        let suggestion = suggest("instal", &HashSet::new());
+        assert_eq!(suggestion.as_deref(), Some("install"));
+    }
+
+    #[test]
+    fn suggest_ignores_distant_typo() {
+        let suggestion = suggest("xyzzy", &HashSet::new());
+        assert_eq!(suggestion, None);
+    }
+
+    #[test]
+    fn suggest_considers_builtin_alias() {
+        let suggestion = suggest("upgrde", &HashSet::new());
+        assert_eq!(suggestion.as_deref(), Some("upgrade"));
+    }
+
+    #[test]
+    fn suggest_considers_pipfile_alias() {
+        let mut aliases = HashSet::new();
+        aliases.insert("ci-install".to_owned());
+        let suggestion = suggest("ci-instal", &aliases);
+        assert_eq!(suggestion.as_deref(), Some("ci-install"));
+    }
+}
@@ -0,0 +1,97 @@
+//! Integration tests for `ripenv build`.
+
+use crate::common::ripenv_command;
+
+/// Write a minimal buildable package: a Pipfile plus a `src/<name>/__init__.py`
+/// layout, matching what `UvContext` needs to synthesize a virtual
+/// `pyproject.toml` with a `[project]` table.
+fn write_minimal_package(dir: &std::path::Path, name: &str) {
+    fs_err::write(
+        dir.join("Pipfile"),
+        "[[source]]\nurl = \"https://pypi.org/simple\"\nverify_ssl = true\nname = \"pypi\"\n\n\
+         [packages]\n\n[dev-packages]\n\n[requires]\npython_version = \"3.12\"\n",
+    )
+    .unwrap();
+
+    let pkg_dir = dir.join("src").join(name);
+    fs_err::create_dir_all(&pkg_dir).unwrap();
+    fs_err::write(pkg_dir.join("__init__.py"), "").unwrap();
+}
+
+/// `ripenv build` with no flags should produce both an sdist and a wheel in
+/// `dist/`.
+#[test]
+fn build_creates_sdist_and_wheel_artifacts() {
+    let dir = tempfile::TempDir::new().unwrap();
+    write_minimal_package(dir.path(), "my_pkg");
+
+    let mut cmd = ripenv_command();
+    cmd.current_dir(dir.path());
+    cmd.arg("build");
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(
+        output.status.success(),
+        "build should succeed, stderr: {stderr}"
+    );
+
+    let dist_dir = dir.path().join("dist");
+    let artifacts: Vec<_> = fs_err::read_dir(&dist_dir)
+        .expect("dist/ should exist")
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+
+    assert!(
+        artifacts.iter().any(|name| name.ends_with(".tar.gz")),
+        "expected an sdist in dist/, found: {artifacts:?}"
+    );
+    assert!(
+        artifacts.iter().any(|name| name.ends_with(".whl")),
+        "expected a wheel in dist/, found: {artifacts:?}"
+    );
+}
+
+/// `ripenv build --sdist` should build only the source distribution.
+#[test]
+fn build_sdist_only_skips_wheel() {
+    let dir = tempfile::TempDir::new().unwrap();
+    write_minimal_package(dir.path(), "my_pkg");
+
+    let mut cmd = ripenv_command();
+    cmd.current_dir(dir.path());
+    cmd.args(["build", "--sdist"]);
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+    assert!(output.status.success());
+
+    let dist_dir = dir.path().join("dist");
+    let artifacts: Vec<_> = fs_err::read_dir(&dist_dir)
+        .expect("dist/ should exist")
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+
+    assert!(artifacts.iter().any(|name| name.ends_with(".tar.gz")));
+    assert!(!artifacts.iter().any(|name| name.ends_with(".whl")));
+}
+
+/// `ripenv build --out-dir` should write artifacts to the custom directory
+/// instead of the default `dist/`.
+#[test]
+fn build_respects_custom_out_dir() {
+    let dir = tempfile::TempDir::new().unwrap();
+    write_minimal_package(dir.path(), "my_pkg");
+
+    let mut cmd = ripenv_command();
+    cmd.current_dir(dir.path());
+    cmd.args(["build", "--out-dir", "build-output"]);
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+    assert!(output.status.success());
+
+    assert!(!dir.path().join("dist").exists());
+    assert!(dir.path().join("build-output").is_dir());
+}
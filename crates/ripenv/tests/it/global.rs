@@ -0,0 +1,120 @@
+//! Integration tests for `ripenv global` — isolate both the manifest and
+//! the venvs directory under the same tempdir by setting `XDG_CONFIG_HOME`
+//! (rather than `RIPENV_GLOBAL_CONFIG`, which only overrides the manifest
+//! path, not `GlobalManifest::venv_dir`).
+
+use crate::common::ripenv_command;
+
+fn global_command(config_home: &std::path::Path) -> std::process::Command {
+    let mut cmd = ripenv_command();
+    cmd.env("XDG_CONFIG_HOME", config_home);
+    cmd
+}
+
+#[test]
+fn global_list_empty_manifest() {
+    let dir = tempfile::TempDir::new().unwrap();
+
+    let mut cmd = global_command(dir.path());
+    cmd.args(["global", "list"]);
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("No tools installed globally."),
+        "got stdout: {stdout:?}"
+    );
+}
+
+#[test]
+fn global_remove_unknown_tool_warns() {
+    let dir = tempfile::TempDir::new().unwrap();
+
+    let mut cmd = global_command(dir.path());
+    cmd.args(["global", "remove", "black"]);
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("not installed globally"),
+        "got stderr: {stderr}"
+    );
+}
+
+#[test]
+fn global_install_already_installed_skips_without_force() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let manifest_path = dir.path().join("ripenv").join("global.toml");
+    fs_err::create_dir_all(manifest_path.parent().unwrap()).unwrap();
+    fs_err::write(
+        &manifest_path,
+        "[tool.black.packages]\nblack = \">=24\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = global_command(dir.path());
+    cmd.args(["global", "install", "black"]);
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(
+        stdout.contains("already installed globally"),
+        "got stdout: {stdout:?}"
+    );
+}
+
+#[test]
+fn global_sync_dry_run_reports_orphans_and_missing() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let ripenv_dir = dir.path().join("ripenv");
+    fs_err::create_dir_all(&ripenv_dir).unwrap();
+    fs_err::write(
+        ripenv_dir.join("global.toml"),
+        "[tool.black.packages]\nblack = \">=24\"\n",
+    )
+    .unwrap();
+    fs_err::create_dir_all(ripenv_dir.join("venvs").join("ruff")).unwrap();
+
+    let mut cmd = global_command(dir.path());
+    cmd.args(["global", "sync", "--dry-run"]);
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("Would remove 'ruff'"),
+        "got stdout: {stdout:?}"
+    );
+    assert!(
+        stdout.contains("Would install 'black'"),
+        "got stdout: {stdout:?}"
+    );
+    // Dry-run must not touch the filesystem.
+    assert!(ripenv_dir.join("venvs").join("ruff").is_dir());
+}
+
+#[test]
+fn global_sync_removes_orphaned_venv() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let ripenv_dir = dir.path().join("ripenv");
+    fs_err::create_dir_all(&ripenv_dir).unwrap();
+    fs_err::write(ripenv_dir.join("global.toml"), "").unwrap();
+    fs_err::create_dir_all(ripenv_dir.join("venvs").join("ruff")).unwrap();
+
+    let mut cmd = global_command(dir.path());
+    cmd.args(["global", "sync"]);
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("Removed 'ruff'"),
+        "got stdout: {stdout:?}"
+    );
+    assert!(!ripenv_dir.join("venvs").join("ruff").exists());
+}
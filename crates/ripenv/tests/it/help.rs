@@ -18,6 +18,8 @@ fn help_shows_all_commands() {
       sync          Sync the virtualenv with the lockfile
       update        Update packages (re-lock then sync)
       run           Run a command in the virtualenv, or a Pipfile script
+      build         Build a source distribution and/or wheel for the project
+      global        Manage standalone CLI tools installed into isolated global venvs
       shell         Spawn a shell with the virtualenv activated
       graph         Display the dependency tree
       requirements  Export locked dependencies as requirements.txt
@@ -26,6 +28,7 @@ fn help_shows_all_commands() {
       verify        Verify the lockfile is up to date with the Pipfile
       check         Deprecated: use `ripenv audit` instead
       audit         Audit installed packages for known vulnerabilities
+      completions   Generate shell completion scripts or man pages
       help          Print this message or the help of the given subcommand(s)
 
     Options:
@@ -126,6 +129,55 @@ fn unknown_command_errors() {
     assert_eq!(output.status.code(), Some(2));
 }
 
+#[test]
+fn typo_suggests_closest_command() {
+    let mut cmd = crate::common::ripenv_command();
+    cmd.arg("instal");
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(
+        stderr.contains("did you mean `ripenv install`?"),
+        "Expected a did-you-mean hint, got: {stderr}"
+    );
+}
+
+#[test]
+fn wildly_wrong_command_gets_no_suggestion() {
+    let mut cmd = crate::common::ripenv_command();
+    cmd.arg("xyzzy");
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(!stderr.contains("did you mean"));
+}
+
+#[test]
+fn completions_bash_writes_script_to_stdout() {
+    let mut cmd = crate::common::ripenv_command();
+    cmd.args(["completions", "bash"]);
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("_ripenv()"));
+}
+
+#[test]
+fn completions_requires_shell_or_man() {
+    let mut cmd = crate::common::ripenv_command();
+    cmd.arg("completions");
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+
+    assert!(!output.status.success());
+}
+
 #[test]
 fn no_args_shows_help() {
     let mut cmd = crate::common::ripenv_command();
@@ -0,0 +1,165 @@
+//! Integration tests for `--format json` NDJSON output on `install`,
+//! `update`, and `update --outdated`.
+
+use std::path::{Path, PathBuf};
+
+use crate::common::ripenv_command;
+
+/// Create a project directory inside a temp dir with a valid Python package name.
+///
+/// Temp dirs often start with `.tmp` which is not a valid package name,
+/// so we create a subdirectory with a clean name.
+fn project_dir(tmp: &tempfile::TempDir) -> PathBuf {
+    let dir = tmp.path().join("test-project");
+    fs_err::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_pipfile(dir: &Path, content: &str) {
+    fs_err::write(dir.join("Pipfile"), content).unwrap();
+}
+
+const MINIMAL_PIPFILE: &str = r#"[[source]]
+url = "https://pypi.org/simple"
+verify_ssl = true
+name = "pypi"
+
+[packages]
+six = "==1.16.0"
+
+[dev-packages]
+
+[requires]
+python_version = "3.12"
+"#;
+
+/// Parse stdout as NDJSON: one `serde_json::Value` per non-empty line.
+/// Fails the test with the full stdout/stderr if any line isn't valid JSON,
+/// so a stray human-readable line (a format-mode regression) is obvious.
+fn parse_ndjson(stdout: &str, stderr: &str) -> Vec<serde_json::Value> {
+    stdout
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).unwrap_or_else(|err| {
+                panic!("expected NDJSON line, got {line:?} ({err}); stderr: {stderr}")
+            })
+        })
+        .collect()
+}
+
+/// `ripenv install --format json` should emit a single `install` NDJSON
+/// event on stdout, with no human-readable prose mixed in.
+///
+/// The before/after package diff needs an existing environment to
+/// compare against, so this first runs a plain `install` to create the
+/// venv, then re-runs `install --format json` to observe its event.
+#[test]
+fn install_format_json_emits_install_event() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let dir = project_dir(&tmp);
+    write_pipfile(&dir, MINIMAL_PIPFILE);
+
+    let first = ripenv_command()
+        .current_dir(&dir)
+        .arg("install")
+        .output()
+        .expect("Failed to execute ripenv");
+    assert!(
+        first.status.success(),
+        "initial ripenv install failed: {}",
+        String::from_utf8_lossy(&first.stderr)
+    );
+
+    let output = ripenv_command()
+        .current_dir(&dir)
+        .args(["--format", "json", "install"])
+        .output()
+        .expect("Failed to execute ripenv");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "ripenv install failed: {stderr}");
+
+    let events = parse_ndjson(&stdout, &stderr);
+    let install_event = events
+        .iter()
+        .find(|event| event["event"] == "install")
+        .unwrap_or_else(|| panic!("expected an install event, got: {events:?}"));
+    assert!(install_event["changes"].is_array());
+}
+
+/// `ripenv update --format json` should emit a single `update` NDJSON
+/// event whose `synced` field reflects that sync ran.
+#[test]
+fn update_format_json_emits_update_event() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let dir = project_dir(&tmp);
+    write_pipfile(&dir, MINIMAL_PIPFILE);
+
+    let lock = ripenv_command()
+        .current_dir(&dir)
+        .arg("lock")
+        .output()
+        .expect("Failed to execute ripenv");
+    assert!(
+        lock.status.success(),
+        "ripenv lock failed: {}",
+        String::from_utf8_lossy(&lock.stderr)
+    );
+
+    let output = ripenv_command()
+        .current_dir(&dir)
+        .args(["--format", "json", "update"])
+        .output()
+        .expect("Failed to execute ripenv");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "ripenv update failed: {stderr}");
+
+    let events = parse_ndjson(&stdout, &stderr);
+    let update_event = events
+        .iter()
+        .find(|event| event["event"] == "update")
+        .unwrap_or_else(|| panic!("expected an update event, got: {events:?}"));
+    assert!(update_event["changes"].is_array());
+    assert_eq!(update_event["synced"], true);
+}
+
+/// `ripenv update --outdated --format json` should emit a single
+/// `outdated` NDJSON event, even when nothing is outdated.
+#[test]
+fn update_outdated_format_json_emits_outdated_event() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let dir = project_dir(&tmp);
+    write_pipfile(&dir, MINIMAL_PIPFILE);
+
+    let lock = ripenv_command()
+        .current_dir(&dir)
+        .arg("lock")
+        .output()
+        .expect("Failed to execute ripenv");
+    assert!(
+        lock.status.success(),
+        "ripenv lock failed: {}",
+        String::from_utf8_lossy(&lock.stderr)
+    );
+
+    let output = ripenv_command()
+        .current_dir(&dir)
+        .args(["--format", "json", "update", "--outdated"])
+        .output()
+        .expect("Failed to execute ripenv");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "ripenv update --outdated failed: {stderr}");
+
+    let events = parse_ndjson(&stdout, &stderr);
+    let outdated_event = events
+        .iter()
+        .find(|event| event["event"] == "outdated")
+        .unwrap_or_else(|| panic!("expected an outdated event, got: {events:?}"));
+    assert!(outdated_event["changes"].is_array());
+}
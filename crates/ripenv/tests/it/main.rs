@@ -5,8 +5,11 @@
 
 pub(crate) mod common;
 
+mod build;
+mod global;
 mod help;
 mod install;
+mod json_format;
 mod lockfile;
 mod parity;
 mod pipfile_parse;
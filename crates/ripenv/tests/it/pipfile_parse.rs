@@ -100,9 +100,9 @@ fn parse_with_scripts() {
         Pipfile::from_path(&fixture("with-scripts")).expect("Failed to parse with-scripts");
 
     assert_eq!(pipfile.scripts.len(), 3);
-    assert_eq!(pipfile.scripts["test"], "pytest -vvs");
-    assert_eq!(pipfile.scripts["serve"], "flask run --debug");
-    assert_eq!(pipfile.scripts["lint"], "ruff check .");
+    assert_eq!(pipfile.scripts["test"].command(), "pytest -vvs");
+    assert_eq!(pipfile.scripts["serve"].command(), "flask run --debug");
+    assert_eq!(pipfile.scripts["lint"].command(), "ruff check .");
 }
 
 #[test]
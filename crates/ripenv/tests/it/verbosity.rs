@@ -1,5 +1,30 @@
 use crate::common::ripenv_command;
 
+#[test]
+fn info_message_goes_to_stdout() {
+    // `global list` with an empty manifest prints a single info message
+    // and touches nothing else, so it pins down where `Printer::info`
+    // actually lands without needing a project or a working uv toolchain.
+    let dir = tempfile::TempDir::new().unwrap();
+
+    let mut cmd = ripenv_command();
+    cmd.env("RIPENV_GLOBAL_CONFIG", dir.path().join("global.toml"));
+    cmd.args(["global", "list"]);
+
+    let output = cmd.output().expect("Failed to execute ripenv");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(
+        stdout.contains("No tools installed globally."),
+        "Expected info message on stdout, got stdout: {stdout:?}, stderr: {stderr:?}"
+    );
+    assert!(
+        stderr.is_empty(),
+        "Expected no stderr for a plain info message, got: {stderr}"
+    );
+}
+
 #[test]
 fn quiet_suppresses_stub_warning() {
     // `install` is now implemented and fails with "No Pipfile found" (exit 2).
@@ -8,12 +33,17 @@ fn quiet_suppresses_stub_warning() {
     cmd.args(["--quiet", "scripts"]);
 
     let output = cmd.output().expect("Failed to execute ripenv");
+    let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
 
     assert_eq!(output.status.code(), Some(1));
+    assert!(
+        stdout.is_empty(),
+        "Expected no stdout with --quiet, got: {stdout}"
+    );
     assert!(
         stderr.is_empty(),
-        "Expected no output with --quiet, got: {stderr}"
+        "Expected no stderr with --quiet, got: {stderr}"
     );
 }
 
@@ -23,12 +53,17 @@ fn quiet_suppresses_check_deprecation() {
     cmd.args(["--quiet", "check"]);
 
     let output = cmd.output().expect("Failed to execute ripenv");
+    let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
 
     assert_eq!(output.status.code(), Some(1));
+    assert!(
+        stdout.is_empty(),
+        "Expected no stdout with --quiet, got: {stdout}"
+    );
     assert!(
         stderr.is_empty(),
-        "Expected no output with --quiet, got: {stderr}"
+        "Expected no stderr with --quiet, got: {stderr}"
     );
 }
 
@@ -39,14 +74,20 @@ fn verbose_flag_accepted() {
     cmd.args(["--verbose", "scripts"]);
 
     let output = cmd.output().expect("Failed to execute ripenv");
+    let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
 
-    // Command still fails (stub) but -v is accepted without error
+    // Command still fails (stub) but -v is accepted without error.
+    // The stub warning is a diagnostic, so it stays on stderr, not stdout.
     assert_eq!(output.status.code(), Some(1));
     assert!(
         stderr.contains("not yet implemented"),
         "Expected stub warning with --verbose, got: {stderr}"
     );
+    assert!(
+        stdout.is_empty(),
+        "Stub warnings are diagnostics and should not appear on stdout, got: {stdout}"
+    );
 }
 
 #[test]